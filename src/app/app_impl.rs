@@ -1,27 +1,63 @@
-use cosmic::app::{Application, Core, Task};
+use cosmic::app::{Application, Core, Task, context_drawer};
 // use cosmic::prelude::*;
-use cosmic::widget::nav_bar;
+use cosmic::iced::window;
+use cosmic::widget::{button, icon, nav_bar, text};
+use std::collections::VecDeque;
 
 use crate::app::AppModel;
 use crate::app::AppState;
 use crate::app::Message;
 use crate::app::UiModel;
+use crate::calc::CalcState;
+use crate::config::APP_ID;
+use crate::config::Config;
+use crate::data::helper as data_helper;
+use crate::editor::KeyEditor;
+use crate::editor::KeypadEditor;
+use crate::editor::PanelComposer;
+use crate::history::Session;
+use crate::history::SessionBrowser;
 
 impl Application for AppModel {
     type Flags = ();
     type Executor = cosmic::executor::Default;
     type Message = Message;
 
-    const APP_ID: &'static str = "com.github.foodmonkey.extendcalc";
+    const APP_ID: &'static str = APP_ID;
 
     fn init(core: Core, _flags: Self::Flags) -> (Self, Task<Self::Message>) {
-        let app = AppModel {
+        let (config_handler, config) = Config::load();
+
+        // pick up the tape from the session that was open last time
+        let session = config
+            .last_session
+            .as_deref()
+            .and_then(|name| Session::from_ron(name).ok());
+
+        let mut app = AppModel {
             core,
             state: AppState::default(),
+            calc: CalcState::with_precision(config.precision).with_engine(config.engine.engine()),
+            evaluating: false,
+            queued: VecDeque::new(),
+            history: session
+                .as_ref()
+                .map(|session| session.tape.clone())
+                .unwrap_or_default(),
+            session: session.map(|session| session.name),
+            config,
+            config_handler,
             ui: UiModel::default(),
+            keypad_editor: KeypadEditor::default(),
+            key_editor: KeyEditor::default(),
+            panel_composer: PanelComposer::default(),
+            session_browser: SessionBrowser::default(),
         };
+        app.calc.set_answers(app.history.results());
+        app.calc.set_memory(app.config.memory.clone());
 
-        let startup_task = Task::future(async move { cosmic::action::app(Message::LoadPanelList) });
+        let startup_task =
+            Task::future(async move { cosmic::action::app(Message::LoadCachedLibrary) });
 
         (app, startup_task)
     }
@@ -38,6 +74,40 @@ impl Application for AppModel {
         Some(&self.ui.navbar)
     }
 
+    fn header_end(&self) -> Vec<cosmic::Element<'_, Self::Message>> {
+        vec![
+            button::icon(icon::from_name("document-open-recent-symbolic"))
+                .on_press(Message::OpenSessionBrowser)
+                .into(),
+            button::icon(icon::from_name("view-grid-symbolic"))
+                .on_press(Message::OpenPanelComposer)
+                .into(),
+            button::icon(icon::from_name("document-edit-symbolic"))
+                .on_press(Message::OpenKeypadEditor)
+                .into(),
+            button::icon(icon::from_name("insert-object-symbolic"))
+                .on_press(Message::OpenKeyEditor)
+                .into(),
+            button::icon(icon::from_name("preferences-system-symbolic"))
+                .on_press(Message::ToggleSettings)
+                .into(),
+        ]
+    }
+
+    fn context_drawer(&self) -> Option<context_drawer::ContextDrawer<'_, Self::Message>> {
+        if !self.core.window.show_context {
+            return None;
+        }
+
+        Some(
+            context_drawer::context_drawer(
+                self.ui.render_settings(&self.config),
+                Message::ToggleSettings,
+            )
+            .title("Settings"),
+        )
+    }
+
     fn on_nav_select(&mut self, id: nav_bar::Id) -> Task<Self::Message> {
         match self.ui.navbar.is_active(id) {
             true => Task::none(),
@@ -45,11 +115,45 @@ impl Application for AppModel {
         }
     }
 
+    // hot reload - the data roots are watched for as long as the app runs
+    fn subscription(&self) -> cosmic::iced::Subscription<Self::Message> {
+        data_helper::watch_library()
+    }
+
     fn update(&mut self, message: Self::Message) -> Task<Self::Message> {
         self.app_update(message)
     }
 
     fn view(&self) -> cosmic::Element<'_, Self::Message> {
-        self.ui.view(&self.state)
+        self.ui.view(
+            &self.state,
+            &self.calc,
+            &self.history,
+            self.session.as_ref(),
+        )
+    }
+
+    // the editor windows - anything else is a window we don't know about
+    fn view_window(&self, id: window::Id) -> cosmic::Element<'_, Self::Message> {
+        if self.keypad_editor.window_id == Some(id) {
+            return self.keypad_editor.view();
+        }
+
+        if self.key_editor.window_id == Some(id) {
+            return self.key_editor.view();
+        }
+
+        if self.panel_composer.window_id == Some(id) {
+            return self.panel_composer.view();
+        }
+
+        match self.session_browser.window_id == Some(id) {
+            true => self.session_browser.view(self.session.as_ref()),
+            false => text("").into(),
+        }
+    }
+
+    fn on_close_requested(&self, id: window::Id) -> Option<Self::Message> {
+        Some(Message::WindowClosed(id))
     }
 }
@@ -2,16 +2,47 @@
 // Tasks may be returned for asynchronous execution of code in the background
 // on the application's async runtime.
 use cosmic::app::Task;
+use cosmic::iced::{Size, window};
+use rust_decimal::Decimal;
 
 use crate::app::AppModel;
 use crate::app::AppState;
+use crate::app::InitState;
 use crate::app::Message;
+use crate::calc::EngineKind;
+use crate::data::Key;
+use crate::data::KeyType;
+use crate::data::ValidationReport;
+use crate::editor::KeyEditorMessage;
+use crate::editor::KeypadEditorMessage;
+use crate::editor::PanelComposerMessage;
+use crate::history::SessionBrowserMessage;
+use crate::history::helper::save_session;
+use crate::ui::KeyId;
 
 use crate::data::helper as data_helper;
 
 impl AppModel {
     pub fn app_update(&mut self, message: Message) -> Task<Message> {
+        // anything else that changes the calculator waits for the key being
+        // worked out, otherwise its result would land on top of the change
+        if self.evaluating
+            && matches!(
+                message,
+                Message::KeyPressed(_)
+                    | Message::RecallResult(_)
+                    | Message::ReplayKeys(_)
+                    | Message::SessionOpened(_)
+            )
+        {
+            self.queued.push_back(message);
+            return Task::none();
+        }
+
         match message {
+            Message::LoadCachedLibrary => data_helper::load_cached_library(),
+            Message::CachedLibraryLoaded(library) => self.ui.cached_library_loaded(library),
+
             Message::LoadPanelList => data_helper::load_panel_list(),
             Message::PanelListLoaded(result) => self.ui.panel_list_loaded(result),
 
@@ -27,31 +58,352 @@ impl AppModel {
             }
             Message::KeypadsLoaded => self.ui.keypads_loaded(),
 
-            Message::LoadKeyGrids => Task::none(),
-            Message::LoadKey(key_ref, count) => Task::none(),
+            Message::LoadKeyGrids(key_list) => data_helper::load_keygrids(key_list),
+            Message::LoadKey(key_id, count) => data_helper::load_key(key_id, count),
+            Message::KeyLoaded(result, key_id, count) => self.ui.key_loaded(result, key_id, count),
+            Message::KeyGridsLoaded => self.ui.keygrids_loaded(),
 
             Message::GenerateSvg(key_identity, render_string, count) => Task::none(),
             Message::SvgGenerated(count) => Task::none(),
+            Message::SVGsLoaded => Task::none(),
             Message::ChangeAppState(state) => {
                 self.state = state;
                 Task::none()
             }
 
             Message::ChangePanel(id) => {
-                println!("msg ChangeKeypad");
                 self.ui.navbar.activate(id);
                 Task::none()
             }
 
-            Message::KeyPressed(key) => {
-                println!("msg KeyPressed {}", key);
+            Message::KeyPressed(key_id) => self.press_key(key_id),
+            Message::KeyEvaluated(key_id, calc) => {
+                self.evaluating = false;
+                let task = match calc {
+                    Some(calc) => {
+                        // the settings may have been changed while it worked
+                        self.calc = *calc;
+                        self.calc.set_engine(self.config.engine.engine());
+                        self.calc.set_precision(self.config.precision);
+                        self.key_pressed(key_id)
+                    }
+                    None => {
+                        eprintln!("failed to work out key {}", key_id);
+                        Task::none()
+                    }
+                };
+
+                // catch up on what waited, until a key has to be worked out again
+                let mut tasks = vec![task];
+                while !self.evaluating
+                    && let Some(message) = self.queued.pop_front()
+                {
+                    tasks.push(self.app_update(message));
+                }
+                Task::batch(tasks)
+            }
+
+            // a past result becomes the entry, as if it had been typed in
+            Message::RecallResult(index) => {
+                if let Some(entry) = self.history.get(index) {
+                    self.calc.enter(entry.result);
+                    self.history.abandon();
+                }
+                Task::none()
+            }
+
+            // the start of a past calculation was typed in with entry keys,
+            // the new start is whatever is in the entry now - so the keys are
+            // pressed again from the first operator on
+            Message::ReplayKeys(index) => {
+                let Some(entry) = self.history.get(index) else {
+                    return Task::none();
+                };
+                let keys: Vec<KeyId> = entry
+                    .keys
+                    .iter()
+                    .skip_while(|key_id| self.is_entry_key(key_id))
+                    .cloned()
+                    .collect();
+
+                let tasks: Vec<Task<Message>> = keys
+                    .into_iter()
+                    .map(|key_id| self.press_key(key_id))
+                    .collect();
+                Task::batch(tasks)
+            }
+
+            Message::OpenSessionBrowser => {
+                if let Some(window_id) = self.session_browser.window_id {
+                    return window::gain_focus(window_id);
+                }
+
+                self.session_browser.start(self.session.as_ref());
+                let (window_id, opened) = window::open(window::Settings {
+                    size: Size::new(720.0, 480.0),
+                    ..Default::default()
+                });
+                self.session_browser.window_id = Some(window_id);
+
+                opened.map(|window_id| {
+                    cosmic::action::app(Message::SessionBrowser(SessionBrowserMessage::Opened(
+                        window_id,
+                    )))
+                })
+            }
+            Message::SessionBrowser(message) => self.session_browser.update(
+                message,
+                &self.history,
+                self.session.as_ref(),
+                &self.ui.keys,
+            ),
+            Message::SessionOpened(session) => {
+                self.history = session.tape;
+                self.calc.clear();
+                self.calc.set_answers(self.history.results());
+                Task::done(cosmic::action::app(Message::SessionChanged(Some(
+                    session.name,
+                ))))
+            }
+            Message::SessionChanged(session) => {
+                if let Some(handler) = &self.config_handler
+                    && let Err(error) = self.config.set_last_session(handler, session.clone())
+                {
+                    eprintln!("failed to save the open session: {}", error);
+                }
+                self.session = session;
+                Task::none()
+            }
+            Message::SessionSaved(result) => {
+                if let Err(error) = result {
+                    eprintln!("failed to save the session: {}", error);
+                }
+                Task::none()
+            }
+
+            Message::ToggleSettings => {
+                self.core.window.show_context = !self.core.window.show_context;
+                Task::none()
+            }
+
+            Message::SelectEngine(index) => {
+                let Some(engine_kind) = EngineKind::AVAILABLE.get(index) else {
+                    return Task::none();
+                };
+
+                self.calc.set_engine(engine_kind.engine());
+                if let Some(handler) = &self.config_handler
+                    && let Err(error) = self.config.set_engine(handler, *engine_kind)
+                {
+                    eprintln!("failed to save engine setting: {}", error);
+                }
+                Task::none()
+            }
+
+            Message::SelectPrecision(index) => {
+                let precision = (index as u32).min(Decimal::MAX_SCALE);
+
+                self.calc.set_precision(precision);
+                if let Some(handler) = &self.config_handler
+                    && let Err(error) = self.config.set_precision(handler, precision)
+                {
+                    eprintln!("failed to save precision setting: {}", error);
+                }
+                Task::none()
+            }
+
+            Message::LibraryCacheSaved(result) => {
+                if let Err(error) = result {
+                    eprintln!("failed to save library cache: {}", error);
+                }
+                Task::none()
+            }
+
+            // only a loaded library is hot reloaded
+            Message::LibraryChanged(change) => match self.state {
+                AppState::Init(InitState::Loaded) => self.ui.library_changed(change),
+                _ => Task::none(),
+            },
+            Message::LibraryReloaded(result) => self.ui.library_reloaded(result),
+            Message::DismissReloadErrors => {
+                self.ui.reload_errors = ValidationReport::default();
+                Task::none()
+            }
+
+            Message::OpenKeypadEditor => {
+                if let Some(window_id) = self.keypad_editor.window_id {
+                    return window::gain_focus(window_id);
+                }
+
+                self.keypad_editor.start(None, &self.ui);
+                let (window_id, opened) = window::open(window::Settings {
+                    size: Size::new(960.0, 760.0),
+                    ..Default::default()
+                });
+                self.keypad_editor.window_id = Some(window_id);
+
+                opened.map(|window_id| {
+                    cosmic::action::app(Message::KeypadEditor(KeypadEditorMessage::Opened(
+                        window_id,
+                    )))
+                })
+            }
+            Message::KeypadEditor(message) => self.keypad_editor.update(message, &self.ui),
+            Message::OpenKeyEditor => {
+                if let Some(window_id) = self.key_editor.window_id {
+                    return window::gain_focus(window_id);
+                }
+
+                self.key_editor.start(None, &self.config);
+                let (window_id, opened) = window::open(window::Settings {
+                    size: Size::new(860.0, 560.0),
+                    ..Default::default()
+                });
+                self.key_editor.window_id = Some(window_id);
+
+                Task::batch([
+                    opened.map(|window_id| {
+                        cosmic::action::app(Message::KeyEditor(KeyEditorMessage::Opened(window_id)))
+                    }),
+                    self.key_editor.preview_task(&self.config),
+                ])
+            }
+            Message::KeyEditor(message) => self.key_editor.update(message, &self.config),
+            Message::OpenPanelComposer => {
+                if let Some(window_id) = self.panel_composer.window_id {
+                    return window::gain_focus(window_id);
+                }
+
+                self.panel_composer.start(&self.ui);
+                let (window_id, opened) = window::open(window::Settings {
+                    size: Size::new(860.0, 600.0),
+                    ..Default::default()
+                });
+                self.panel_composer.window_id = Some(window_id);
+
+                opened.map(|window_id| {
+                    cosmic::action::app(Message::PanelComposer(PanelComposerMessage::Opened(
+                        window_id,
+                    )))
+                })
+            }
+            Message::PanelComposer(message) => self.panel_composer.update(message, &self.ui),
+            Message::WindowClosed(window_id) => {
+                if self.keypad_editor.window_id == Some(window_id) {
+                    self.keypad_editor.window_id = None;
+                }
+                if self.key_editor.window_id == Some(window_id) {
+                    self.key_editor.window_id = None;
+                }
+                if self.panel_composer.window_id == Some(window_id) {
+                    self.panel_composer.window_id = None;
+                }
+                if self.session_browser.window_id == Some(window_id) {
+                    self.session_browser.window_id = None;
+                }
+                Task::none()
+            }
+
+            Message::ValidationFailed(report) => {
+                self.state = AppState::Error(report);
                 Task::none()
             }
 
             Message::Error(error) => {
-                self.state = AppState::Error(error);
+                self.state = AppState::Error(error.into());
                 Task::none()
             }
         }
     }
+
+    // an engine that runs another program works the key out off the UI
+    // thread, keys pressed in the meantime wait their turn
+    fn press_key(&mut self, key_id: KeyId) -> Task<Message> {
+        if self.evaluating {
+            self.queued.push_back(Message::KeyPressed(key_id));
+            return Task::none();
+        }
+        let Some(key) = self.ui.keys.get(&key_id) else {
+            return Task::none();
+        };
+
+        if !self.calc.is_blocking() {
+            self.calc.press(key.key_type());
+            return self.key_pressed(key_id);
+        }
+
+        self.evaluating = true;
+        let mut calc = self.calc.clone();
+        let key_type = key.key_type().clone();
+        Task::future(async move {
+            let calc = tokio::task::spawn_blocking(move || {
+                calc.press(&key_type);
+                Box::new(calc)
+            })
+            .await
+            .ok();
+            cosmic::action::app(Message::KeyEvaluated(key_id, calc))
+        })
+    }
+
+    // a finished calculation goes on the history tape, a clear or an
+    // error throws away the keys pressed so far. keys that only put the
+    // entry into memory aren't part of a calculation, a change to memory
+    // is saved straight away
+    fn key_pressed(&mut self, key_id: KeyId) -> Task<Message> {
+        if self.calc.memory() != &self.config.memory {
+            self.save_memory();
+        }
+        if !self.is_memory_key(&key_id) {
+            self.history.press(key_id);
+        }
+        match self.calc.take_calculation() {
+            Some(calculation) => {
+                self.history.record(calculation);
+
+                // the open session is kept up to date on disk
+                if let Some(session) = &self.session {
+                    return save_session(session, &self.history);
+                }
+            }
+            None if self.calc.error().is_some() || self.calc.is_clear() => self.history.abandon(),
+            None => {}
+        }
+        Task::none()
+    }
+
+    fn save_memory(&mut self) {
+        let memory = self.calc.memory().clone();
+        match &self.config_handler {
+            Some(handler) => {
+                if let Err(error) = self.config.set_memory(handler, memory) {
+                    eprintln!("failed to save the memory registers: {}", error);
+                }
+            }
+            None => self.config.memory = memory,
+        }
+    }
+
+    // digits, the decimal point and memory recall only ever build up the entry
+    fn is_entry_key(&self, key_id: &KeyId) -> bool {
+        match self.ui.keys.get(key_id).map(Key::key_type) {
+            Some(KeyType::Operand { .. }) => true,
+            Some(KeyType::Internal { operation, .. }) => {
+                matches!(operation.as_str(), "DECIMAL" | "MEMORY_RECALL")
+            }
+            _ => false,
+        }
+    }
+
+    fn is_memory_key(&self, key_id: &KeyId) -> bool {
+        match self.ui.keys.get(key_id).map(Key::key_type) {
+            Some(KeyType::Internal { operation, .. }) => {
+                matches!(
+                    operation.as_str(),
+                    "MEMORY_ADD" | "MEMORY_SUBTRACT" | "MEMORY_CLEAR"
+                )
+            }
+            _ => false,
+        }
+    }
 }
@@ -1,65 +1,134 @@
 // this is the definition of the appmodel
 use cosmic::Core;
+use cosmic::cosmic_config;
+use cosmic::iced::window;
 use cosmic::widget::nav_bar;
 
 use crate::app::AppState;
+use crate::calc::CalcState;
+use crate::config::Config;
+use crate::editor::KeyEditor;
+use crate::editor::KeyEditorMessage;
+use crate::editor::KeypadEditor;
+use crate::editor::KeypadEditorMessage;
+use crate::editor::PanelComposer;
+use crate::editor::PanelComposerMessage;
+use crate::history::HistoryTape;
+use crate::history::Session;
+use crate::history::SessionBrowser;
+use crate::history::SessionBrowserMessage;
+use std::collections::VecDeque;
+use std::path::PathBuf;
 
+use crate::data::AssembledLibrary;
+use crate::data::DataError;
 use crate::data::Key;
-use crate::data::KeyRef;
+use crate::data::KeyList;
 use crate::data::Keypad;
 use crate::data::KeypadList;
 use crate::data::KeypadRef;
+use crate::data::LibraryChange;
+use crate::data::LibraryReload;
 use crate::data::Panel;
 use crate::data::PanelList;
 use crate::data::PanelRef;
+use crate::data::ValidationReport;
 
 use crate::ui::KeyGrids;
+use crate::ui::KeyId;
 use crate::ui::KeySvg;
 use crate::ui::Keypads;
+use crate::ui::Keys;
 use crate::ui::Panels;
 
 #[derive(Debug, Clone)]
 pub enum Message {
+    LoadCachedLibrary,
+    CachedLibraryLoaded(Option<Box<AssembledLibrary>>),
+
     LoadPanelList,
-    PanelListLoaded(Result<PanelList, String>),
+    PanelListLoaded(Result<PanelList, DataError>),
 
     LoadPanels(PanelList),
     LoadPanel(PanelRef, usize),
-    PanelLoaded(Result<Panel, String>, usize),
+    PanelLoaded(Result<Panel, DataError>, usize),
     PanelsLoaded,
 
     LoadKeypads(KeypadList),
     LoadKeypad(KeypadRef, usize),
-    KeypadLoaded(Result<Keypad, String>, KeypadRef, usize),
+    KeypadLoaded(Result<Keypad, DataError>, KeypadRef, usize),
     KeypadsLoaded,
 
-    LoadKeyGrids,
-    LoadKey(KeyRef, usize),
-    KeyLoaded(Result<Key, String>, KeyGridRef, usize),
+    LoadKeyGrids(KeyList),
+    LoadKey(KeyId, usize),
+    KeyLoaded(Result<Key, DataError>, KeyId, usize),
     GenerateSvg(KeyId, String, usize),
     SvgGenerated(usize),
     KeyGridsLoaded,
     SVGsLoaded,
+    ValidationFailed(ValidationReport),
+    LibraryCacheSaved(Result<(), DataError>),
+
+    LibraryChanged(LibraryChange),
+    LibraryReloaded(Result<LibraryReload, ValidationReport>),
+    DismissReloadErrors,
 
     ChangeAppState(AppState),
     ChangePanel(nav_bar::Id),
-    KeyPressed(String),
+    KeyPressed(KeyId),
+    KeyEvaluated(KeyId, Option<Box<CalcState>>),
+    RecallResult(usize),
+    ReplayKeys(usize),
+
+    OpenSessionBrowser,
+    SessionBrowser(SessionBrowserMessage),
+    SessionOpened(Session),
+    SessionChanged(Option<String>),
+    SessionSaved(Result<PathBuf, DataError>),
+
+    ToggleSettings,
+    SelectEngine(usize),
+    SelectPrecision(usize),
+
+    OpenKeypadEditor,
+    KeypadEditor(KeypadEditorMessage),
+    OpenKeyEditor,
+    KeyEditor(KeyEditorMessage),
+    OpenPanelComposer,
+    PanelComposer(PanelComposerMessage),
+    WindowClosed(window::Id),
 
-    Error(String),
+    Error(DataError),
 }
 
 pub struct AppModel {
     pub core: Core,
     pub state: AppState,
+    pub calc: CalcState,
+    // a key being worked out off the UI thread, and the messages that
+    // have to wait for it
+    pub evaluating: bool,
+    pub queued: VecDeque<Message>,
+    pub history: HistoryTape,
+    pub session: Option<String>,
+    pub config: Config,
+    pub config_handler: Option<cosmic_config::Config>,
     pub ui: UiModel,
+    pub keypad_editor: KeypadEditor,
+    pub key_editor: KeyEditor,
+    pub panel_composer: PanelComposer,
+    pub session_browser: SessionBrowser,
 }
 
 pub struct UiModel {
     pub navbar: nav_bar::Model,
     pub panels: Panels,
     pub keypads: Keypads,
+    pub keys: Keys,
     pub keygrids: KeyGrids,
     pub key_svg: KeySvg,
+    pub load_errors: ValidationReport,
+    pub reload_errors: ValidationReport,
 }
 
 impl Default for UiModel {
@@ -68,8 +137,11 @@ impl Default for UiModel {
             navbar: nav_bar::Model::default(),
             panels: Panels::default(),
             keypads: Keypads::default(),
+            keys: Keys::default(),
             keygrids: KeyGrids::default(),
             key_svg: KeySvg::new(),
+            load_errors: ValidationReport::default(),
+            reload_errors: ValidationReport::default(),
         }
     }
 }
@@ -1,4 +1,6 @@
 // state of the app
+use crate::data::ValidationReport;
+
 #[derive(Debug, Default, Clone)]
 pub enum InitState {
     #[default]
@@ -10,7 +12,7 @@ pub enum InitState {
 pub enum AppState {
     Init(InitState),
     Ready,
-    Error(String),
+    Error(ValidationReport),
 }
 
 impl Default for AppState {
@@ -26,7 +28,7 @@ impl std::fmt::Display for AppState {
             AppState::Init(InitState::Loading) => "Init Loading",
             AppState::Init(InitState::Loaded) => "Init Loaded",
             AppState::Ready => "Ready",
-            AppState::Error(err) => return write!(f, "Error: {}", err),
+            AppState::Error(report) => return write!(f, "Error: {}", report),
         };
         write!(f, "{}", name)
     }
@@ -0,0 +1,19 @@
+pub mod helper;
+mod model;
+
+pub(crate) use model::Arity;
+pub(crate) use model::CalcEngine;
+pub(crate) use model::CalcError;
+pub(crate) use model::CalcState;
+pub(crate) use model::Calculation;
+pub(crate) use model::DEFAULT_REGISTER;
+pub(crate) use model::EngineKind;
+pub(crate) use model::Memory;
+pub(crate) use model::NativeEngine;
+pub(crate) use model::OPERAND_NAMES;
+pub(crate) use model::OPERATIONS;
+#[cfg(feature = "qalc")]
+pub(crate) use model::QalcEngine;
+pub(crate) use model::TermSegment;
+pub(crate) use model::TermTemplate;
+pub(crate) use model::Token;
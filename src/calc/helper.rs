@@ -0,0 +1,9 @@
+mod check_term;
+mod evaluate;
+mod resolve_term;
+mod tokenize;
+
+pub(crate) use check_term::check_term;
+pub(crate) use evaluate::evaluate;
+pub(crate) use resolve_term::resolve_term;
+pub(crate) use tokenize::tokenize;
@@ -0,0 +1,103 @@
+// type-check an operator term against its declared arity - this runs when
+// the key is loaded so a broken template never makes it onto a keypad
+
+use rust_decimal::Decimal;
+
+use crate::calc::Arity;
+use crate::calc::CalcError;
+use crate::calc::OPERAND_NAMES;
+use crate::calc::TermTemplate;
+use crate::calc::helper::evaluate;
+use crate::calc::helper::resolve_term;
+
+pub fn check_term(qalc_term: &TermTemplate, arity: Arity) -> Result<(), CalcError> {
+    let operands = qalc_term.operands();
+    let expected: Vec<char> = OPERAND_NAMES.chars().take(arity.operands()).collect();
+
+    // no placeholders is fine for the fixed arities, otherwise the
+    // template has to use exactly the operand names the arity binds
+    let shorthand = operands.is_empty() && !matches!(arity, Arity::Nary(_));
+    if !shorthand && operands != expected {
+        return Err(CalcError::ArityMismatch(qalc_term.to_string(), arity));
+    }
+
+    // and it has to expand into something the evaluator can parse
+    let sample = vec![Decimal::ONE; arity.operands()];
+    let answers = vec![Decimal::ONE; qalc_term.deepest_answer()];
+    let expression = resolve_term(qalc_term, arity, &sample, &answers)?;
+    match evaluate(&expression) {
+        Ok(_)
+        | Err(CalcError::DivisionByZero)
+        | Err(CalcError::Domain(_, _))
+        | Err(CalcError::Overflow) => Ok(()),
+        Err(error) => Err(CalcError::InvalidTerm(
+            qalc_term.to_string(),
+            Box::new(error),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(qalc_term: &str, arity: Arity) -> Result<(), CalcError> {
+        check_term(&TermTemplate::try_from(qalc_term.to_string())?, arity)
+    }
+
+    #[test]
+    fn terms_that_fit() {
+        let cases = [
+            (" * 1.15", Arity::PostfixUnary),
+            (" sin ", Arity::PrefixUnary),
+            (" ^ ", Arity::InfixBinary),
+            ("sqrt({})", Arity::PrefixUnary),
+            ("1/{x}", Arity::PostfixUnary),
+            ("{x} ^ {y}", Arity::InfixBinary),
+            ("({x} + {y} + {z}) / 3", Arity::Nary(3)),
+            ("{x} + {Ans[2]}", Arity::PostfixUnary),
+            // fine to load even though 1 / (1 - 1) can't be worked out
+            ("{x} / ({x} - 1)", Arity::PostfixUnary),
+        ];
+
+        for (qalc_term, arity) in cases {
+            assert_eq!(check(qalc_term, arity), Ok(()), "{}", qalc_term);
+        }
+    }
+
+    #[test]
+    fn arity_mismatch() {
+        let cases = [
+            ("{x} ^ {y}", Arity::PostfixUnary),
+            ("{x}", Arity::InfixBinary),
+            ("{y}", Arity::PrefixUnary),
+            ("{x} + {z}", Arity::Nary(2)),
+            (" + ", Arity::Nary(2)),
+        ];
+
+        for (qalc_term, arity) in cases {
+            assert_eq!(
+                check(qalc_term, arity),
+                Err(CalcError::ArityMismatch(qalc_term.to_string(), arity)),
+                "{}",
+                qalc_term
+            );
+        }
+    }
+
+    #[test]
+    fn terms_that_dont_parse() {
+        assert!(matches!(
+            check(" * * ", Arity::PostfixUnary),
+            Err(CalcError::InvalidTerm(_, _))
+        ));
+        assert!(matches!(
+            check("{x} +", Arity::PostfixUnary),
+            Err(CalcError::InvalidTerm(_, _))
+        ));
+        assert!(matches!(
+            check("{x", Arity::PostfixUnary),
+            Err(CalcError::UnclosedPlaceholder(_))
+        ));
+    }
+}
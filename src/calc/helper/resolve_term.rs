@@ -0,0 +1,75 @@
+// expand a key's operator term into a full expression for the engine,
+// with the operands and any past answers filled in. a template without
+// operand placeholders is shorthand, the arity says where the operands go:
+//
+//   PrefixUnary   " sin "   ->  sin ({x})
+//   PostfixUnary  " * 1.15" ->  {x} * 1.15
+//   InfixBinary   " ^ "     ->  {x} ^ {y}
+//
+// answers are the results on the history tape, oldest first
+
+use rust_decimal::Decimal;
+
+use crate::calc::Arity;
+use crate::calc::CalcError;
+use crate::calc::OPERAND_NAMES;
+use crate::calc::TermSegment;
+use crate::calc::TermTemplate;
+
+pub fn resolve_term(
+    qalc_term: &TermTemplate,
+    arity: Arity,
+    operands: &[Decimal],
+    answers: &[Decimal],
+) -> Result<String, CalcError> {
+    if operands.len() != arity.operands() {
+        return Err(CalcError::MissingOperand(qalc_term.to_string()));
+    }
+
+    let operand = |name: char| {
+        OPERAND_NAMES
+            .chars()
+            .position(|candidate| candidate == name)
+            .and_then(|index| operands.get(index))
+            .map(|value| bracketed(*value))
+            .ok_or_else(|| CalcError::MissingOperand(qalc_term.to_string()))
+    };
+
+    let answer = |back: usize| {
+        answers
+            .len()
+            .checked_sub(back)
+            .and_then(|index| answers.get(index))
+            .map(|value| bracketed(*value))
+            .ok_or(CalcError::NoAnswer(back))
+    };
+
+    let mut expression = String::new();
+    for segment in qalc_term.segments() {
+        match segment {
+            TermSegment::Text(text) => expression.push_str(text),
+            TermSegment::Operand(name) => expression.push_str(&operand(*name)?),
+            TermSegment::Answer(back) => expression.push_str(&answer(*back)?),
+        }
+    }
+
+    let term = expression.trim();
+    if qalc_term.operands().is_empty() {
+        return match arity {
+            Arity::PrefixUnary => Ok(format!("{} ({})", term, operand('x')?)),
+            Arity::PostfixUnary => Ok(format!("{} {}", operand('x')?, term)),
+            Arity::InfixBinary => Ok(format!("{} {} {}", operand('x')?, term, operand('y')?)),
+            Arity::Nary(_) => Err(CalcError::ArityMismatch(qalc_term.to_string(), arity)),
+        };
+    }
+
+    Ok(term.to_string())
+}
+
+// negative values go in brackets so "-2 ^2" doesn't turn into -(2^2)
+fn bracketed(value: Decimal) -> String {
+    match value.is_sign_negative() {
+        true => format!("({})", value),
+        false => value.to_string(),
+    }
+}
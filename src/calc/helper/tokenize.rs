@@ -0,0 +1,65 @@
+// split an expression string into tokens - whitespace is only a separator
+// so " * 1.15" and "*1.15" give the same result
+
+use rust_decimal::Decimal;
+
+use crate::calc::CalcError;
+use crate::calc::Token;
+
+pub fn tokenize(expression: &str) -> Result<Vec<Token>, CalcError> {
+    let mut tokens = Vec::new();
+    let mut chars = expression.chars().peekable();
+
+    while let Some(&c) = chars.peek() {
+        match c {
+            c if c.is_whitespace() => {
+                chars.next();
+            }
+
+            c if c.is_ascii_digit() || c == '.' => {
+                let mut number = String::new();
+                while let Some(&d) = chars.peek() {
+                    if !(d.is_ascii_digit() || d == '.') {
+                        break;
+                    }
+                    number.push(d);
+                    chars.next();
+                }
+                let value = number
+                    .parse::<Decimal>()
+                    .map_err(|_| CalcError::InvalidNumber(number.clone()))?;
+                tokens.push(Token::Number(value));
+            }
+
+            c if c.is_alphabetic() => {
+                let mut ident = String::new();
+                while let Some(&d) = chars.peek() {
+                    if !d.is_alphanumeric() {
+                        break;
+                    }
+                    ident.push(d);
+                    chars.next();
+                }
+                tokens.push(Token::Ident(ident));
+            }
+
+            _ => {
+                let token = match c {
+                    '+' => Token::Plus,
+                    '-' => Token::Minus,
+                    '*' => Token::Star,
+                    '/' => Token::Slash,
+                    '^' => Token::Caret,
+                    '!' => Token::Bang,
+                    '(' => Token::LeftParen,
+                    ')' => Token::RightParen,
+                    other => return Err(CalcError::UnexpectedChar(other)),
+                };
+                tokens.push(token);
+                chars.next();
+            }
+        }
+    }
+
+    Ok(tokens)
+}
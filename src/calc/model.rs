@@ -0,0 +1,29 @@
+mod arity;
+mod calc_engine;
+mod calc_error;
+mod calc_state;
+mod calculation;
+mod engine_kind;
+mod memory;
+mod native_engine;
+#[cfg(feature = "qalc")]
+mod qalc_engine;
+mod term_template;
+mod token;
+
+pub use arity::Arity;
+pub use calc_engine::CalcEngine;
+pub use calc_error::CalcError;
+pub use calc_state::CalcState;
+pub use calc_state::OPERATIONS;
+pub use calculation::Calculation;
+pub use engine_kind::EngineKind;
+pub use memory::DEFAULT_REGISTER;
+pub use memory::Memory;
+pub use native_engine::NativeEngine;
+#[cfg(feature = "qalc")]
+pub use qalc_engine::QalcEngine;
+pub use term_template::OPERAND_NAMES;
+pub use term_template::TermSegment;
+pub use term_template::TermTemplate;
+pub use token::Token;
@@ -0,0 +1,38 @@
+// the declared shape of an operator term - this is how many operands it
+// takes and, when the template has no placeholders, where they go
+//
+//   PrefixUnary   " sin "     ->  sin ({x})
+//   PostfixUnary  "! "        ->  {x} !
+//   InfixBinary   " ^ "       ->  {x} ^ {y}
+//   Nary(n)       needs n named placeholders in the template
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
+pub enum Arity {
+    PrefixUnary,
+    PostfixUnary,
+    InfixBinary,
+    Nary(usize),
+}
+
+impl Arity {
+    pub fn operands(&self) -> usize {
+        match self {
+            Arity::PrefixUnary | Arity::PostfixUnary => 1,
+            Arity::InfixBinary => 2,
+            Arity::Nary(count) => *count,
+        }
+    }
+}
+
+impl std::fmt::Display for Arity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Arity::PrefixUnary => write!(f, "prefix unary"),
+            Arity::PostfixUnary => write!(f, "postfix unary"),
+            Arity::InfixBinary => write!(f, "infix binary"),
+            Arity::Nary(count) => write!(f, "{}-ary", count),
+        }
+    }
+}
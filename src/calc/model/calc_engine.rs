@@ -0,0 +1,15 @@
+// the expression engine behind the calculator - resolve_term expands a key's
+// operator term into a full expression and hands it to one of these
+
+use rust_decimal::Decimal;
+
+use crate::calc::CalcError;
+
+pub trait CalcEngine: std::fmt::Debug + Send + Sync {
+    fn evaluate(&self, expression: &str) -> Result<Decimal, CalcError>;
+
+    // an engine that waits on another program is kept off the UI thread
+    fn is_blocking(&self) -> bool {
+        false
+    }
+}
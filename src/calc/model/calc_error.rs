@@ -0,0 +1,56 @@
+// this is the error for everything the expression engine can go wrong on
+
+use thiserror::Error;
+
+use crate::calc::Arity;
+
+#[derive(Debug, Error, Clone, PartialEq)]
+pub enum CalcError {
+    #[error("unexpected character '{0}' in expression")]
+    UnexpectedChar(char),
+
+    #[error("invalid number '{0}'")]
+    InvalidNumber(String),
+
+    #[error("unexpected '{0}' in expression")]
+    UnexpectedToken(String),
+
+    #[error("expression ended too soon")]
+    UnexpectedEnd,
+
+    #[error("unknown function '{0}'")]
+    UnknownFunction(String),
+
+    #[error("operator term '{0}' is missing an operand")]
+    MissingOperand(String),
+
+    #[error("there is no answer {0} back on the history tape")]
+    NoAnswer(usize),
+
+    #[error("unclosed placeholder in term '{0}'")]
+    UnclosedPlaceholder(String),
+
+    #[error("'{0}' is not a valid placeholder")]
+    InvalidPlaceholder(String),
+
+    #[error("term '{0}' doesn't fit a {1} operator")]
+    ArityMismatch(String, Arity),
+
+    #[error("term '{0}' is not a valid expression: {1}")]
+    InvalidTerm(String, Box<CalcError>),
+
+    #[error("unknown internal operation '{0}'")]
+    UnknownOperation(String),
+
+    #[error("division by zero")]
+    DivisionByZero,
+
+    #[error("result is too large")]
+    Overflow,
+
+    #[error("engine error: {0}")]
+    Engine(String),
+
+    #[error("{0} is outside the domain of {1}")]
+    Domain(String, String),
+}
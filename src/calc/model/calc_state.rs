@@ -0,0 +1,503 @@
+// the calculator entry state machine - this is what a key press drives
+//
+//   Operand keys append their digit to the input buffer
+//   Operator keys either apply straight away (unary terms like " * 1.15")
+//   or get queued as the pending operator (binary and n-ary terms)
+//   Internal keys are entry control - EQUALS commits the pending operator,
+//   or for an n-ary operator that still wants more, moves to the next operand
+//   - and the memory keys, which work on the entry and a memory register
+//
+// results are rounded to the configured number of decimal places and
+// the expressions themselves are worked out by the configured engine
+//
+// every expression handed to the engine is kept until a result comes out,
+// then the lot is handed over as a Calculation for the history tape
+//
+// like the answers, the memory registers outlast a clear
+
+use rust_decimal::Decimal;
+use std::sync::Arc;
+
+use crate::calc::Arity;
+use crate::calc::CalcEngine;
+use crate::calc::CalcError;
+use crate::calc::Calculation;
+use crate::calc::Memory;
+use crate::calc::NativeEngine;
+use crate::calc::TermTemplate;
+use crate::calc::helper::resolve_term;
+use crate::config::Config;
+use crate::data::KeyType;
+
+// every operation an Internal key can name
+pub const OPERATIONS: [&str; 7] = [
+    "EQUALS",
+    "DECIMAL",
+    "CLEAR",
+    "MEMORY_ADD",
+    "MEMORY_SUBTRACT",
+    "MEMORY_RECALL",
+    "MEMORY_CLEAR",
+];
+
+#[derive(Debug, Clone)]
+pub struct CalcState {
+    input: String,
+    accumulator: Vec<Decimal>,
+    pending: Option<(TermTemplate, Arity)>,
+    last_result: Option<Decimal>,
+    error: Option<CalcError>,
+    precision: u32,
+    engine: Arc<dyn CalcEngine>,
+    start: Option<Decimal>,
+    expressions: Vec<String>,
+    calculation: Option<Calculation>,
+    answers: Vec<Decimal>,
+    memory: Memory,
+}
+
+impl CalcState {
+    pub fn with_precision(precision: u32) -> Self {
+        Self {
+            input: String::new(),
+            accumulator: Vec::new(),
+            pending: None,
+            last_result: None,
+            error: None,
+            precision,
+            engine: Arc::new(NativeEngine),
+            start: None,
+            expressions: Vec::new(),
+            calculation: None,
+            answers: Vec::new(),
+            memory: Memory::default(),
+        }
+    }
+
+    pub fn with_engine(mut self, engine: Arc<dyn CalcEngine>) -> Self {
+        self.engine = engine;
+        self
+    }
+
+    pub fn set_precision(&mut self, precision: u32) {
+        self.precision = precision;
+    }
+
+    pub fn set_engine(&mut self, engine: Arc<dyn CalcEngine>) {
+        self.engine = engine;
+    }
+
+    pub fn press(&mut self, key_type: &KeyType) {
+        if self.error.is_some() {
+            self.clear();
+        }
+
+        let outcome = match key_type {
+            KeyType::Operand { value } => {
+                self.push_operand(*value);
+                Ok(())
+            }
+            KeyType::Operator { qalc_term, arity } => self.apply_operator(qalc_term, *arity),
+            KeyType::Internal {
+                operation,
+                register,
+            } => self.internal(operation, register),
+        };
+
+        if let Err(error) = outcome {
+            self.error = Some(error);
+        }
+    }
+
+    // what the display should show right now
+    pub fn display(&self) -> String {
+        if let Some(error) = &self.error {
+            return format!("Error: {}", error);
+        }
+
+        match (
+            self.input.is_empty(),
+            self.last_result,
+            self.accumulator.last(),
+        ) {
+            (false, _, _) => self.input.clone(),
+            (true, Some(result), _) => result.to_string(),
+            (true, None, Some(accumulator)) => accumulator.to_string(),
+            (true, None, None) => "0".to_string(),
+        }
+    }
+
+    pub fn last_result(&self) -> Option<Decimal> {
+        self.last_result
+    }
+
+    pub fn error(&self) -> Option<&CalcError> {
+        self.error.as_ref()
+    }
+
+    // the calculation the last key press finished, if it finished one
+    pub fn take_calculation(&mut self) -> Option<Calculation> {
+        self.calculation.take()
+    }
+
+    // nothing entered, nothing pending and nothing to show
+    pub fn is_clear(&self) -> bool {
+        self.input.is_empty()
+            && self.accumulator.is_empty()
+            && self.pending.is_none()
+            && self.last_result.is_none()
+            && self.error.is_none()
+    }
+
+    // true if a key press has to wait on the engine, see CalcEngine
+    pub fn is_blocking(&self) -> bool {
+        self.engine.is_blocking()
+    }
+
+    // true while an operator is waiting for its other operands
+    pub fn has_pending(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    // type a whole number in at once - replaces whatever is being entered
+    pub fn enter(&mut self, value: Decimal) {
+        if self.error.is_some() {
+            self.clear();
+        }
+        self.last_result = None;
+        self.input = value.to_string();
+    }
+
+    // a clear doesn't touch the answers, they belong to the tape, or what's
+    // in memory
+    pub fn clear(&mut self) {
+        let answers = std::mem::take(&mut self.answers);
+        let memory = std::mem::take(&mut self.memory);
+        *self = Self::with_precision(self.precision).with_engine(self.engine.clone());
+        self.answers = answers;
+        self.memory = memory;
+    }
+
+    // the results {Ans} can reach back to, oldest first
+    pub fn set_answers(&mut self, answers: Vec<Decimal>) {
+        self.answers = answers;
+    }
+
+    pub fn memory(&self) -> &Memory {
+        &self.memory
+    }
+
+    pub fn set_memory(&mut self, memory: Memory) {
+        self.memory = memory;
+    }
+
+    fn push_operand(&mut self, value: Decimal) {
+        self.last_result = None;
+        if self.input == "0" {
+            self.input.clear();
+        }
+        self.input.push_str(&value.to_string());
+    }
+
+    fn push_decimal(&mut self) {
+        self.last_result = None;
+        if self.input.is_empty() {
+            self.input.push('0');
+        }
+        if !self.input.contains('.') {
+            self.input.push('.');
+        }
+    }
+
+    // the current entry is the input buffer if there is one, otherwise
+    // the last result so operators can chain onto a previous answer
+    fn entry(&self) -> Result<Decimal, CalcError> {
+        match self.input.is_empty() {
+            true => Ok(self
+                .last_result
+                .or(self.accumulator.last().copied())
+                .unwrap_or_default()),
+            false => self
+                .input
+                .trim_end_matches('.')
+                .parse::<Decimal>()
+                .map_err(|_| CalcError::InvalidNumber(self.input.clone())),
+        }
+    }
+
+    fn round(&self, value: Decimal) -> Decimal {
+        value.round_dp(self.precision).normalize()
+    }
+
+    fn apply_operator(&mut self, qalc_term: &TermTemplate, arity: Arity) -> Result<(), CalcError> {
+        let entry = self.entry()?;
+
+        // the first operator is where a calculation starts
+        if self.pending.is_none() && self.expressions.is_empty() {
+            self.start = Some(entry);
+        }
+
+        match arity.operands() {
+            // with an operator pending the result is only the next operand,
+            // the calculation isn't finished until that operator is
+            1 => {
+                let result = self.evaluate(qalc_term, arity, &[entry])?;
+                match self.pending {
+                    Some(_) => self.hold(result),
+                    None => {
+                        self.input.clear();
+                        self.finish(result);
+                    }
+                }
+            }
+
+            // commit anything already queued and wait for the other operands
+            _ => {
+                let left = self.commit(entry)?;
+                self.input.clear();
+                self.last_result = None;
+                self.accumulator = vec![left];
+                self.pending = Some((qalc_term.clone(), arity));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn internal(&mut self, operation: &str, register: &str) -> Result<(), CalcError> {
+        match operation {
+            "EQUALS" => {
+                let entry = self.entry()?;
+                self.input.clear();
+
+                if self.wants_more_operands() {
+                    self.accumulator.push(entry);
+                    return Ok(());
+                }
+
+                let result = self.commit(entry)?;
+                self.accumulator.clear();
+                self.finish(result);
+                Ok(())
+            }
+            "DECIMAL" => {
+                self.push_decimal();
+                Ok(())
+            }
+            "CLEAR" => {
+                self.clear();
+                Ok(())
+            }
+            "MEMORY_ADD" => {
+                let entry = self.entry()?;
+                self.memory.add(register, entry)?;
+                self.hold(entry);
+                Ok(())
+            }
+            "MEMORY_SUBTRACT" => {
+                let entry = self.entry()?;
+                self.memory.subtract(register, entry)?;
+                self.hold(entry);
+                Ok(())
+            }
+            // recalled as if it had been typed in
+            "MEMORY_RECALL" => {
+                self.last_result = None;
+                self.input = self.memory.recall(register).to_string();
+                Ok(())
+            }
+            "MEMORY_CLEAR" => {
+                self.memory.clear(register);
+                Ok(())
+            }
+            _ => Err(CalcError::UnknownOperation(operation.to_string())),
+        }
+    }
+
+    // the entry stays on the display after it goes into memory, or a unary
+    // key works out an operand, but the next digit starts a new number
+    fn hold(&mut self, entry: Decimal) {
+        self.input.clear();
+        self.last_result = Some(entry);
+    }
+
+    // true while a pending n-ary operator still needs more than the entry
+    fn wants_more_operands(&self) -> bool {
+        match &self.pending {
+            Some((_, arity)) => self.accumulator.len() + 1 < arity.operands(),
+            None => false,
+        }
+    }
+
+    // apply the pending operator (if any) to the accumulator and entry
+    fn commit(&mut self, entry: Decimal) -> Result<Decimal, CalcError> {
+        match self.pending.take() {
+            Some((qalc_term, arity)) => {
+                let mut operands = std::mem::take(&mut self.accumulator);
+                operands.push(entry);
+                self.evaluate(&qalc_term, arity, &operands)
+            }
+            None => Ok(entry),
+        }
+    }
+
+    // hand the expanded term to the engine, keeping the expression
+    fn evaluate(
+        &mut self,
+        qalc_term: &TermTemplate,
+        arity: Arity,
+        operands: &[Decimal],
+    ) -> Result<Decimal, CalcError> {
+        let expression = resolve_term(qalc_term, arity, operands, &self.answers)?;
+        let result = self.engine.evaluate(&expression)?;
+        self.expressions.push(expression);
+        Ok(self.round(result))
+    }
+
+    // a result is out - anything the engine worked out on the way is a
+    // finished calculation, a bare number followed by EQUALS isn't
+    fn finish(&mut self, result: Decimal) {
+        self.last_result = Some(result);
+
+        let start = self.start.take();
+        let expressions = std::mem::take(&mut self.expressions);
+        if let Some(start) = start
+            && !expressions.is_empty()
+        {
+            self.calculation = Some(Calculation {
+                start,
+                expression: expressions.join(" → "),
+                result,
+            });
+            self.answers.push(result);
+        }
+    }
+}
+
+impl Default for CalcState {
+    fn default() -> Self {
+        Self::with_precision(Config::default().precision)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decimal(value: &str) -> Decimal {
+        value.parse().unwrap()
+    }
+
+    fn operator(qalc_term: &str, arity: Arity) -> KeyType {
+        KeyType::Operator {
+            qalc_term: TermTemplate::try_from(qalc_term.to_string()).unwrap(),
+            arity,
+        }
+    }
+
+    // the business library keys
+    fn plus_gst() -> KeyType {
+        operator(" * 1.15", Arity::PostfixUnary)
+    }
+
+    fn ex_gst() -> KeyType {
+        operator(" / 1.15", Arity::PostfixUnary)
+    }
+
+    fn gst() -> KeyType {
+        operator(" * 3 / 23", Arity::PostfixUnary)
+    }
+
+    fn equals() -> KeyType {
+        KeyType::Internal {
+            operation: "EQUALS".to_string(),
+            register: String::new(),
+        }
+    }
+
+    #[test]
+    fn gst_round_trips_exactly() {
+        for value in ["100", "7", "99.99", "0.01", "123456.78"] {
+            let mut calc = CalcState::default();
+            calc.enter(decimal(value));
+            calc.press(&plus_gst());
+            calc.press(&ex_gst());
+            assert_eq!(calc.last_result(), Some(decimal(value)), "{}", value);
+        }
+    }
+
+    #[test]
+    fn gst_keys() {
+        let mut calc = CalcState::default();
+        calc.enter(decimal("100"));
+        calc.press(&plus_gst());
+        assert_eq!(calc.last_result(), Some(decimal("115")));
+
+        calc.press(&gst());
+        assert_eq!(calc.last_result(), Some(decimal("15")));
+
+        calc.enter(decimal("230"));
+        calc.press(&ex_gst());
+        assert_eq!(calc.last_result(), Some(decimal("200")));
+        assert_eq!(calc.display(), "200");
+    }
+
+    #[test]
+    fn results_are_rounded_to_the_precision() {
+        let mut calc = CalcState::with_precision(2);
+        calc.enter(decimal("100"));
+        calc.press(&ex_gst());
+        assert_eq!(calc.last_result(), Some(decimal("86.96")));
+
+        let mut calc = CalcState::with_precision(10);
+        calc.enter(decimal("10"));
+        calc.press(&operator(" / ", Arity::InfixBinary));
+        calc.enter(decimal("3"));
+        calc.press(&equals());
+        assert_eq!(calc.last_result(), Some(decimal("3.3333333333")));
+        assert_eq!(calc.display(), "3.3333333333");
+    }
+
+    #[test]
+    fn rounding_drops_trailing_zeros() {
+        let mut calc = CalcState::with_precision(4);
+        calc.enter(decimal("2.50"));
+        calc.press(&operator(" * 2", Arity::PostfixUnary));
+        assert_eq!(calc.display(), "5");
+    }
+
+    #[test]
+    fn memory_overflow_is_an_error() {
+        let memory_add = KeyType::Internal {
+            operation: "MEMORY_ADD".to_string(),
+            register: String::new(),
+        };
+        let mut calc = CalcState::default();
+        calc.enter(Decimal::MAX);
+        calc.press(&memory_add);
+        assert_eq!(calc.memory().recall(""), Decimal::MAX);
+
+        calc.enter(Decimal::ONE);
+        calc.press(&memory_add);
+        assert_eq!(calc.error(), Some(&CalcError::Overflow));
+        assert_eq!(calc.memory().recall(""), Decimal::MAX);
+    }
+
+    #[test]
+    fn unary_key_inside_a_pending_operator() {
+        let mut calc = CalcState::default();
+        calc.enter(decimal("2"));
+        calc.press(&operator(" ^ ", Arity::InfixBinary));
+        calc.enter(decimal("9"));
+        calc.press(&operator(" sqrt({}) ", Arity::PrefixUnary));
+        assert_eq!(calc.display(), "3");
+        assert_eq!(calc.take_calculation(), None);
+
+        calc.press(&equals());
+        assert_eq!(calc.last_result(), Some(decimal("8")));
+        let calculation = calc.take_calculation().unwrap();
+        assert_eq!(calculation.start, decimal("2"));
+        assert_eq!(calculation.result, decimal("8"));
+        assert_eq!(calc.answers, [decimal("8")]);
+    }
+}
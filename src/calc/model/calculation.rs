@@ -0,0 +1,11 @@
+// a finished calculation - what it started from, the expression the engine
+// was given (each step of a chained calculation, in order) and the result
+
+use rust_decimal::Decimal;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Calculation {
+    pub start: Decimal,
+    pub expression: String,
+    pub result: Decimal,
+}
@@ -0,0 +1,46 @@
+// which engine the user has picked in settings - this is what gets
+// stored in the config, engine() turns it into the real thing
+
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+use crate::calc::CalcEngine;
+use crate::calc::NativeEngine;
+
+#[derive(Debug, Default, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
+pub enum EngineKind {
+    #[default]
+    Native,
+    Qalc,
+}
+
+impl EngineKind {
+    // the engines this build can actually offer in settings
+    #[cfg(feature = "qalc")]
+    pub const AVAILABLE: &'static [EngineKind] = &[EngineKind::Native, EngineKind::Qalc];
+    #[cfg(not(feature = "qalc"))]
+    pub const AVAILABLE: &'static [EngineKind] = &[EngineKind::Native];
+
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            EngineKind::Native => "Built-in",
+            EngineKind::Qalc => "Qalculate (qalc)",
+        }
+    }
+
+    // qalc is only there when built with the qalc feature - otherwise
+    // we quietly fall back to the built-in engine
+    pub fn engine(&self) -> Arc<dyn CalcEngine> {
+        match self {
+            #[cfg(feature = "qalc")]
+            EngineKind::Qalc => Arc::new(crate::calc::QalcEngine::new()),
+            _ => Arc::new(NativeEngine),
+        }
+    }
+}
+
+impl std::fmt::Display for EngineKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
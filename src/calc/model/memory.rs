@@ -0,0 +1,106 @@
+// the memory registers - the classic M register plus any number of named
+// ones, each holding a running total. a key with no register name uses M
+//
+// a register that's back at zero is dropped, so the names left are just
+// the registers the display indicator needs to show
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use crate::calc::CalcError;
+
+pub const DEFAULT_REGISTER: &str = "M";
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Memory {
+    registers: BTreeMap<String, Decimal>,
+}
+
+impl Memory {
+    // a total too big for a Decimal leaves the register as it was
+    pub fn add(&mut self, register: &str, value: Decimal) -> Result<(), CalcError> {
+        let total = self
+            .recall(register)
+            .checked_add(value)
+            .ok_or(CalcError::Overflow)?;
+        self.store(register, total);
+        Ok(())
+    }
+
+    pub fn subtract(&mut self, register: &str, value: Decimal) -> Result<(), CalcError> {
+        let total = self
+            .recall(register)
+            .checked_sub(value)
+            .ok_or(CalcError::Overflow)?;
+        self.store(register, total);
+        Ok(())
+    }
+
+    // a register that was never set holds zero
+    pub fn recall(&self, register: &str) -> Decimal {
+        self.registers
+            .get(Self::name(register))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    pub fn clear(&mut self, register: &str) {
+        self.registers.remove(Self::name(register));
+    }
+
+    // the registers holding something, by name
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.registers.keys().map(String::as_str)
+    }
+
+    fn store(&mut self, register: &str, value: Decimal) {
+        match value.is_zero() {
+            true => self.clear(register),
+            false => {
+                self.registers
+                    .insert(Self::name(register).to_string(), value.normalize());
+            }
+        }
+    }
+
+    fn name(register: &str) -> &str {
+        match register.trim() {
+            "" => DEFAULT_REGISTER,
+            register => register,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn running_totals() {
+        let mut memory = Memory::default();
+        memory.add("", Decimal::from(5)).unwrap();
+        memory.subtract("M", Decimal::from(2)).unwrap();
+        memory.add("tax", Decimal::new(15, 1)).unwrap();
+        assert_eq!(memory.recall(""), Decimal::from(3));
+        assert_eq!(memory.recall("tax"), Decimal::new(15, 1));
+        assert_eq!(memory.names().collect::<Vec<_>>(), ["M", "tax"]);
+
+        // back at zero, the register goes
+        memory.subtract("tax", Decimal::new(15, 1)).unwrap();
+        assert_eq!(memory.names().collect::<Vec<_>>(), ["M"]);
+    }
+
+    #[test]
+    fn overflow() {
+        let mut memory = Memory::default();
+        memory.add("", Decimal::MAX).unwrap();
+        assert_eq!(memory.add("", Decimal::ONE), Err(CalcError::Overflow));
+        assert_eq!(memory.recall(""), Decimal::MAX);
+
+        memory.clear("");
+        memory.add("", Decimal::MIN).unwrap();
+        assert_eq!(memory.subtract("", Decimal::ONE), Err(CalcError::Overflow));
+        assert_eq!(memory.recall(""), Decimal::MIN);
+    }
+}
@@ -0,0 +1,16 @@
+// the built-in engine - the recursive descent evaluator in calc::helper
+
+use rust_decimal::Decimal;
+
+use crate::calc::CalcEngine;
+use crate::calc::CalcError;
+use crate::calc::helper::evaluate;
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NativeEngine;
+
+impl CalcEngine for NativeEngine {
+    fn evaluate(&self, expression: &str) -> Result<Decimal, CalcError> {
+        evaluate(expression)
+    }
+}
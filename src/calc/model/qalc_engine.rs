@@ -0,0 +1,209 @@
+// the optional qalculate backend - each expression is handed to a local
+// qalc binary in terse mode and the answer read back from stdout
+//
+// qalc works in radians unless told otherwise, it's set to degrees to
+// match the built-in engine. a qalc that doesn't answer in time is killed,
+// its output is read as it goes so a long answer can't fill the pipe and
+// stall it
+//
+// the program is swappable so a stub script can stand in for qalc
+
+use rust_decimal::Decimal;
+use std::io::Read;
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+use std::thread;
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+use crate::calc::CalcEngine;
+use crate::calc::CalcError;
+
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Clone)]
+pub struct QalcEngine {
+    program: PathBuf,
+    timeout: Duration,
+}
+
+impl QalcEngine {
+    pub fn new() -> Self {
+        Self::with_program("qalc")
+    }
+
+    pub fn with_program(program: impl Into<PathBuf>) -> Self {
+        Self {
+            program: program.into(),
+            timeout: TIMEOUT,
+        }
+    }
+
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    fn failed(&self, error: std::io::Error) -> CalcError {
+        CalcError::Engine(format!("{}: {}", self.program.display(), error))
+    }
+}
+
+impl Default for QalcEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CalcEngine for QalcEngine {
+    fn evaluate(&self, expression: &str) -> Result<Decimal, CalcError> {
+        let mut child = Command::new(&self.program)
+            .arg("-t")
+            .arg("-set")
+            .arg("angle deg")
+            .arg(expression)
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| self.failed(e))?;
+
+        let stdout = drain(child.stdout.take());
+        let stderr = drain(child.stderr.take());
+
+        let deadline = Instant::now() + self.timeout;
+        let status = loop {
+            if let Some(status) = child.try_wait().map_err(|e| self.failed(e))? {
+                break status;
+            }
+            if Instant::now() >= deadline {
+                // the readers are left to finish once the pipes close
+                let _ = child.kill();
+                let _ = child.wait();
+                return Err(CalcError::Engine(format!(
+                    "{} didn't answer within {:?}",
+                    self.program.display(),
+                    self.timeout
+                )));
+            }
+            thread::sleep(Duration::from_millis(10));
+        };
+
+        let stdout = String::from_utf8_lossy(&stdout.join().unwrap_or_default()).into_owned();
+        if !status.success() {
+            let stderr = stderr.join().unwrap_or_default();
+            return Err(CalcError::Engine(
+                String::from_utf8_lossy(&stderr).trim().to_string(),
+            ));
+        }
+
+        // qalc marks rounded answers with ≈ - the value is still usable
+        let answer = stdout.trim().trim_start_matches(['=', '≈']).trim();
+        answer
+            .parse::<Decimal>()
+            .or_else(|_| Decimal::from_scientific(answer))
+            .map_err(|_| CalcError::Engine(format!("unexpected answer '{}'", answer)))
+    }
+
+    fn is_blocking(&self) -> bool {
+        true
+    }
+}
+
+// read a pipe to the end on its own thread
+fn drain(pipe: Option<impl Read + Send + 'static>) -> JoinHandle<Vec<u8>> {
+    thread::spawn(move || {
+        let mut output = Vec::new();
+        if let Some(mut pipe) = pipe {
+            let _ = pipe.read_to_end(&mut output);
+        }
+        output
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::os::unix::fs::PermissionsExt;
+
+    // a shell script standing in for qalc, removed again when it's dropped
+    struct Stub(PathBuf);
+
+    impl Stub {
+        fn new(name: &str, script: &str) -> Self {
+            let path =
+                std::env::temp_dir().join(format!("qalc-stub-{}-{}", std::process::id(), name));
+            std::fs::write(&path, format!("#!/bin/sh\n{}\n", script)).unwrap();
+            std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755)).unwrap();
+            Self(path)
+        }
+
+        fn evaluate(&self, expression: &str) -> Result<Decimal, CalcError> {
+            QalcEngine::with_program(&self.0).evaluate(expression)
+        }
+    }
+
+    impl Drop for Stub {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_file(&self.0);
+        }
+    }
+
+    #[test]
+    fn answers() {
+        let stub = Stub::new("answers", "echo '= 42'");
+        assert_eq!(stub.evaluate("6 * 7"), Ok(Decimal::from(42)));
+
+        let stub = Stub::new("rounded", "echo '≈ 0.3333333333'");
+        assert_eq!(stub.evaluate("1 / 3"), Ok(Decimal::new(3333333333, 10)));
+
+        let stub = Stub::new("scientific", "echo '1.5E3'");
+        assert_eq!(stub.evaluate("1500"), Ok(Decimal::from(1500)));
+    }
+
+    #[test]
+    fn asks_for_degrees() {
+        let stub = Stub::new(
+            "degrees",
+            r#"[ "$1 $2 $3" = "-t -set angle deg" ] && echo "= $4" || exit 1"#,
+        );
+        assert_eq!(stub.evaluate("12"), Ok(Decimal::from(12)));
+    }
+
+    #[test]
+    fn failures() {
+        let stub = Stub::new("fails", "echo 'error: bad expression' >&2; exit 1");
+        assert_eq!(
+            stub.evaluate("1 +"),
+            Err(CalcError::Engine("error: bad expression".to_string()))
+        );
+
+        let stub = Stub::new("unexpected", "echo 'x = 2'");
+        assert_eq!(
+            stub.evaluate("x"),
+            Err(CalcError::Engine("unexpected answer 'x = 2'".to_string()))
+        );
+
+        let missing = QalcEngine::with_program("/nonexistent/qalc");
+        assert!(matches!(missing.evaluate("1"), Err(CalcError::Engine(_))));
+    }
+
+    // more than a pipe holds, on both outputs
+    #[test]
+    fn long_output() {
+        let stub = Stub::new(
+            "long",
+            "head -c 200000 /dev/zero | tr '\\0' ' '; head -c 200000 /dev/zero >&2; echo '= 7'",
+        );
+        assert_eq!(stub.evaluate("7"), Ok(Decimal::from(7)));
+    }
+
+    #[test]
+    fn times_out() {
+        let stub = Stub::new("slow", "sleep 5; echo '= 1'");
+        let engine = QalcEngine::with_program(&stub.0).with_timeout(Duration::from_millis(100));
+        let started = Instant::now();
+        assert!(matches!(engine.evaluate("1"), Err(CalcError::Engine(_))));
+        assert!(started.elapsed() < Duration::from_secs(2));
+    }
+}
@@ -0,0 +1,205 @@
+// a parsed operator term - literal expression text with named placeholders
+//
+//   {x} {y} {z} ...  a named operand, one lowercase letter
+//   {}               shorthand for {x}
+//   {Ans}            the last result on the history tape
+//   {Ans[n]}         the result n back on the tape, {Ans[1]} is {Ans}
+//
+// operands are bound to names in the order x, y, z, a, b, c ... so a
+// binary term uses {x} and {y} and a 3-ary term uses {x} {y} {z}
+
+use serde::{Deserialize, Serialize};
+
+use crate::calc::CalcError;
+
+pub const OPERAND_NAMES: &str = "xyzabcdefghijklmnopqrstuvw";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TermSegment {
+    Text(String),
+    Operand(char),
+    Answer(usize),
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(try_from = "String", into = "String")]
+pub struct TermTemplate {
+    source: String,
+    segments: Vec<TermSegment>,
+}
+
+impl TermTemplate {
+    pub fn segments(&self) -> &[TermSegment] {
+        &self.segments
+    }
+
+    // the distinct operand names used, in binding order
+    pub fn operands(&self) -> Vec<char> {
+        OPERAND_NAMES
+            .chars()
+            .filter(|name| self.segments.contains(&TermSegment::Operand(*name)))
+            .collect()
+    }
+
+    // how far back the tape the term reaches, 0 if it doesn't use Ans
+    pub fn deepest_answer(&self) -> usize {
+        self.segments
+            .iter()
+            .filter_map(|segment| match segment {
+                TermSegment::Answer(back) => Some(*back),
+                _ => None,
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.source
+    }
+}
+
+impl TryFrom<String> for TermTemplate {
+    type Error = CalcError;
+
+    fn try_from(source: String) -> Result<Self, Self::Error> {
+        let mut segments = Vec::new();
+        let mut text = String::new();
+        let mut chars = source.chars();
+
+        while let Some(c) = chars.next() {
+            match c {
+                '{' => {
+                    let mut name = String::new();
+                    loop {
+                        match chars.next() {
+                            Some('}') => break,
+                            Some(c) => name.push(c),
+                            None => return Err(CalcError::UnclosedPlaceholder(source)),
+                        }
+                    }
+
+                    let segment = match name.as_str() {
+                        "" => TermSegment::Operand('x'),
+                        "Ans" => TermSegment::Answer(1),
+                        name if name.len() == 1 && OPERAND_NAMES.contains(name) => {
+                            TermSegment::Operand(name.chars().next().unwrap_or('x'))
+                        }
+                        name => match answer_back(name) {
+                            Some(back) => TermSegment::Answer(back),
+                            None => return Err(CalcError::InvalidPlaceholder(name.to_string())),
+                        },
+                    };
+
+                    if !text.is_empty() {
+                        segments.push(TermSegment::Text(std::mem::take(&mut text)));
+                    }
+                    segments.push(segment);
+                }
+                '}' => return Err(CalcError::InvalidPlaceholder(c.to_string())),
+                c => text.push(c),
+            }
+        }
+
+        if !text.is_empty() {
+            segments.push(TermSegment::Text(text));
+        }
+
+        Ok(Self { source, segments })
+    }
+}
+
+// Ans[n] - n has to be 1 or more, Ans[1] being the last result
+fn answer_back(name: &str) -> Option<usize> {
+    name.strip_prefix("Ans[")?
+        .strip_suffix(']')?
+        .trim()
+        .parse()
+        .ok()
+        .filter(|back| *back > 0)
+}
+
+impl From<TermTemplate> for String {
+    fn from(template: TermTemplate) -> Self {
+        template.source
+    }
+}
+
+impl std::fmt::Display for TermTemplate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.source)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(source: &str) -> Result<TermTemplate, CalcError> {
+        TermTemplate::try_from(source.to_string())
+    }
+
+    #[test]
+    fn placeholders() {
+        use TermSegment::*;
+
+        let text = |text: &str| Text(text.to_string());
+        let cases = [
+            (" * 1.15", vec![text(" * 1.15")]),
+            ("sqrt({})", vec![text("sqrt("), Operand('x'), text(")")]),
+            ("{x} ^ {y}", vec![Operand('x'), text(" ^ "), Operand('y')]),
+            ("{y}-{x}", vec![Operand('y'), text("-"), Operand('x')]),
+            ("{x} + {Ans}", vec![Operand('x'), text(" + "), Answer(1)]),
+            ("{Ans[3]}", vec![Answer(3)]),
+            ("{Ans[ 2 ]}", vec![Answer(2)]),
+        ];
+
+        for (source, segments) in cases {
+            let template = parse(source).unwrap();
+            assert_eq!(template.segments(), segments.as_slice(), "{}", source);
+            assert_eq!(template.as_str(), source);
+        }
+    }
+
+    #[test]
+    fn operands_in_binding_order() {
+        let cases = [
+            (" * 1.15", vec![]),
+            ("{}", vec!['x']),
+            ("{x} * {x}", vec!['x']),
+            ("{y} - {x}", vec!['x', 'y']),
+            ("{x} + {y} + {z}", vec!['x', 'y', 'z']),
+        ];
+
+        for (source, operands) in cases {
+            assert_eq!(parse(source).unwrap().operands(), operands, "{}", source);
+        }
+    }
+
+    #[test]
+    fn deepest_answer() {
+        assert_eq!(parse("{x}").unwrap().deepest_answer(), 0);
+        assert_eq!(parse("{Ans}").unwrap().deepest_answer(), 1);
+        assert_eq!(parse("{Ans[2]} + {Ans[5]}").unwrap().deepest_answer(), 5);
+    }
+
+    #[test]
+    fn broken_placeholders() {
+        let unclosed = |source: &str| CalcError::UnclosedPlaceholder(source.to_string());
+        let invalid = |name: &str| CalcError::InvalidPlaceholder(name.to_string());
+        let cases = [
+            ("{x", unclosed("{x")),
+            ("1 + {", unclosed("1 + {")),
+            ("{X}", invalid("X")),
+            ("{xy}", invalid("xy")),
+            ("{1}", invalid("1")),
+            ("{Ans[0]}", invalid("Ans[0]")),
+            ("{Ans[}", invalid("Ans[")),
+            ("{ans}", invalid("ans")),
+            ("x}", invalid("}")),
+        ];
+
+        for (source, error) in cases {
+            assert_eq!(parse(source), Err(error), "{}", source);
+        }
+    }
+}
@@ -0,0 +1,9 @@
+pub mod helper;
+mod model;
+
+pub(crate) use model::CliCommand;
+pub(crate) use model::CliError;
+pub(crate) use model::LintProblem;
+pub(crate) use model::LintReport;
+pub(crate) use model::Severity;
+pub(crate) use model::USAGE;
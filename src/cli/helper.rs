@@ -0,0 +1,11 @@
+mod lint_root;
+mod run_cli;
+mod run_eval;
+mod run_export;
+mod run_validate;
+
+pub(crate) use lint_root::lint_root;
+pub(crate) use run_cli::run_cli;
+pub(crate) use run_eval::run_eval;
+pub(crate) use run_export::run_export;
+pub(crate) use run_validate::run_validate;
@@ -0,0 +1,619 @@
+// lint everything under one data root - every RON file is read and parsed
+// straight from the root, on its own (so one bad file doesn't hide the
+// rest), then the lot is assembled into a UiModel and put through the same
+// validation as the GUI. nothing goes through the library cache and the
+// user and system roots aren't looked at, so the answer only depends on
+// the root being linted
+//
+// references the root doesn't satisfy itself are looked up in the built-in
+// data, so a keypad repo can use the built-in keys
+//
+// every problem in a file that parsed points at the line and column it's
+// about - validation only knows which ref is wrong, so the ref's place in
+// its list picks which key_id, grid_position or id field to point at
+
+use ron::error::Span;
+use serde::de::DeserializeOwned;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+
+use crate::app::UiModel;
+use crate::data::DataDir;
+use crate::data::DataError;
+use crate::data::DataErrorKind;
+use crate::data::DataOrigin;
+use crate::data::DataRoot;
+use crate::data::DataSource;
+use crate::data::Key;
+use crate::data::KeyType;
+use crate::data::Keypad;
+use crate::data::KeypadRef;
+use crate::data::Panel;
+use crate::data::PanelList;
+use crate::data::PanelRef;
+use crate::data::ValidationReport;
+use crate::data::helper::find_field;
+use crate::data::helper::find_fields;
+use crate::data::helper::find_source_in;
+use crate::ui::GridPosition;
+use crate::ui::KeyId;
+use crate::ui::KeypadView;
+use crate::ui::PanelView;
+
+pub fn lint_root(root: &Path) -> ValidationReport {
+    let mut linter = Linter::new(root);
+
+    linter.load_keys(root);
+    linter.load_keypads(root);
+    linter.load_panels(root);
+    linter.resolve_outside_root();
+    linter.validate();
+    linter.check_unused_keys();
+
+    linter.report
+}
+
+struct Linter {
+    // the root being linted, then the built-in data
+    roots: [DataRoot; 2],
+    ui: UiModel,
+    report: ValidationReport,
+    // the text of every file that was read, to find fields in
+    contents: HashMap<PathBuf, String>,
+    root_keys: Vec<(KeyId, DataSource)>,
+}
+
+impl Linter {
+    fn new(root: &Path) -> Self {
+        Self {
+            roots: [
+                DataRoot {
+                    origin: DataOrigin::User,
+                    path: root.to_path_buf(),
+                },
+                DataRoot {
+                    origin: DataOrigin::BuiltIn,
+                    path: PathBuf::from(DataDir::Data),
+                },
+            ],
+            ui: UiModel::default(),
+            report: ValidationReport::default(),
+            contents: HashMap::new(),
+            root_keys: Vec::new(),
+        }
+    }
+
+    // keys - key_definitions/<library>/<id>.ron
+    fn load_keys(&mut self, root: &Path) {
+        let key_dir = root.join(DataDir::KeyDefinitions);
+        for path in ron_files(&key_dir) {
+            self.report
+                .push(DataError::new(DataErrorKind::StrayFile).with_path(&path));
+        }
+
+        for library_dir in sub_dirs(&key_dir) {
+            let library = file_name(&library_dir);
+            for path in ron_files(&library_dir) {
+                let key_id = KeyId::new(&library, file_name(&path));
+                let source = on_disk(path);
+
+                let Some(key) = self.parse::<Key>(&source, &key_id) else {
+                    continue;
+                };
+                self.check_id(&source, &key_id.id, &key.id);
+
+                // a bad term is reported against the term, the key is still
+                // there as far as the keypads are concerned
+                if let Err(error) = key.key_type().check() {
+                    let field = match key.key_type() {
+                        KeyType::Internal { .. } => "operation",
+                        _ => "qalc_term",
+                    };
+                    let span = self.find(&source.path, field);
+                    let error = DataError::from(error)
+                        .with_path(&source.path)
+                        .with_id(&key_id);
+                    self.push(error, span);
+                }
+
+                self.ui.keys.insert(key_id.clone(), key);
+                self.root_keys.push((key_id, source));
+            }
+        }
+    }
+
+    // keypads - keypad_definitions/<id>.ron
+    fn load_keypads(&mut self, root: &Path) {
+        for path in ron_files(&root.join(DataDir::KeypadDefinitions)) {
+            let keypad_ref = KeypadRef::new(file_name(&path));
+            let source = on_disk(path);
+
+            if let Some(mut keypad) = self.parse::<Keypad>(&source, &keypad_ref.id) {
+                self.check_id(&source, &keypad_ref.id, &keypad.id);
+                keypad.source = source;
+                self.ui
+                    .keypads
+                    .insert(&keypad_ref, &KeypadView::from(keypad));
+            }
+        }
+    }
+
+    // panels - panel_definitions/<id>.ron and the panel_list.ron
+    fn load_panels(&mut self, root: &Path) {
+        let mut panel_list = None;
+        for path in ron_files(&root.join(DataDir::PanelDefinitions)) {
+            let panel_ref = PanelRef {
+                id: file_name(&path),
+            };
+            let source = on_disk(path);
+
+            if panel_ref.id == "panel_list" {
+                panel_list = self
+                    .parse::<PanelList>(&source, "panel_list")
+                    .map(|list| (list, source));
+                continue;
+            }
+
+            if let Some(mut panel) = self.parse::<Panel>(&source, &panel_ref.id) {
+                self.check_id(&source, &panel_ref.id, &panel.id);
+                panel.source = source;
+                self.ui.panels.push(PanelView::from(panel));
+            }
+        }
+
+        let Some((list, source)) = panel_list else {
+            return;
+        };
+        for (index, panel_ref) in (&list).into_iter().enumerate() {
+            let found = self.ui.panels.contains(&panel_ref.id)
+                || find_source_in(&self.roots, &Panel::ron_path(panel_ref)).is_ok();
+            if !found {
+                let span = self.find_in_list(&source.path, "panel_refs", "id", index);
+                let error = DataError::new(DataErrorKind::UnknownPanel(panel_ref.id.clone()))
+                    .with_path(&source.path)
+                    .with_id("panel_list");
+                self.push(error, span);
+            }
+        }
+    }
+
+    // pull in keypads and keys the root refers to but doesn't define - if
+    // they aren't built in either, validation reports them as unknown
+    fn resolve_outside_root(&mut self) {
+        let keypad_refs: Vec<KeypadRef> = (&self.ui.panels)
+            .into_iter()
+            .flat_map(|panel_view| panel_view.keypads.clone())
+            .collect();
+        for keypad_ref in keypad_refs {
+            if !self.ui.keypads.contains(&keypad_ref)
+                && let Ok(source) = find_source_in(&self.roots, &Keypad::ron_path(&keypad_ref))
+                && source.origin == DataOrigin::BuiltIn
+                && let Ok(mut keypad) = self.read::<Keypad>(&source)
+            {
+                keypad.source = source;
+                self.ui
+                    .keypads
+                    .insert(&keypad_ref, &KeypadView::from(keypad));
+            }
+        }
+
+        let key_ids: Vec<KeyId> = (&self.ui.keypads)
+            .into_iter()
+            .flat_map(|(_, keypad_view)| {
+                keypad_view
+                    .keys
+                    .iter()
+                    .map(|key_ref| key_ref.key_id.clone())
+            })
+            .collect();
+        for key_id in key_ids {
+            if !self.ui.keys.contains(&key_id)
+                && let Ok(source) = find_source_in(&self.roots, &Key::ron_path(&key_id))
+                && source.origin == DataOrigin::BuiltIn
+                && let Ok(key) = self.read::<Key>(&source)
+            {
+                self.ui.keys.insert(key_id, key);
+            }
+        }
+    }
+
+    // the GUI's validation, one panel and keypad at a time so each problem
+    // can be pointed at the ref it's about
+    fn validate(&mut self) {
+        let mut located: Vec<(DataError, Option<Span>)> = Vec::new();
+
+        for panel_view in &self.ui.panels {
+            for error in self.ui.validate_panel(panel_view) {
+                let span = self.locate_in_panel(panel_view, &error.kind);
+                located.push((error, span));
+            }
+        }
+
+        for (keypad_ref, keypad_view) in &self.ui.keypads {
+            for error in self.ui.validate_keypad(keypad_ref, keypad_view) {
+                let span = self.locate_in_keypad(keypad_view, &error.kind);
+                located.push((error, span));
+            }
+        }
+
+        for (error, span) in located {
+            self.push(error, span);
+        }
+    }
+
+    fn check_unused_keys(&mut self) {
+        for (key_id, source) in std::mem::take(&mut self.root_keys) {
+            let used = (&self.ui.keypads).into_iter().any(|(_, keypad_view)| {
+                keypad_view
+                    .keys
+                    .iter()
+                    .any(|key_ref| key_ref.key_id == key_id)
+            });
+
+            if !used {
+                let span = self.find(&source.path, "id");
+                let error = DataError::new(DataErrorKind::UnusedKey(key_id.clone()))
+                    .with_path(&source.path)
+                    .with_id(&key_id);
+                self.push(error, span);
+            }
+        }
+    }
+
+    // the id inside a file has to match the file name, that's how it's found
+    fn check_id(&mut self, source: &DataSource, expected: &str, found: &str) {
+        if expected == found {
+            return;
+        }
+
+        let span = self.find(&source.path, "id");
+        let error = DataError::new(DataErrorKind::IdMismatch {
+            expected: expected.to_string(),
+            found: found.to_string(),
+        })
+        .with_path(&source.path)
+        .with_id(expected);
+
+        self.push(error, span);
+    }
+
+    fn locate_in_panel(&self, panel_view: &PanelView, kind: &DataErrorKind) -> Option<Span> {
+        let keypads = &panel_view.keypads;
+        let (field, index) = match kind {
+            DataErrorKind::UnknownKeypad(keypad_id) => (
+                "id",
+                keypads
+                    .iter()
+                    .position(|keypad_ref| &keypad_ref.id == keypad_id)?,
+            ),
+            DataErrorKind::KeypadOutOfBounds {
+                keypad_id,
+                position,
+                ..
+            } => (
+                "row",
+                keypads.iter().position(|keypad_ref| {
+                    &keypad_ref.id == keypad_id && keypad_ref.grid_position() == *position
+                })?,
+            ),
+            // the second keypad in the cell is the one that clashes
+            DataErrorKind::DuplicateKeypadPosition(position) => (
+                "row",
+                keypads
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, keypad_ref)| keypad_ref.grid_position() == *position)
+                    .nth(1)?
+                    .0,
+            ),
+            _ => return None,
+        };
+
+        self.find_in_list(&panel_view.source.path, "keypads", field, index)
+    }
+
+    fn locate_in_keypad(&self, keypad_view: &KeypadView, kind: &DataErrorKind) -> Option<Span> {
+        let keys = &keypad_view.keys;
+        let key = |key_id: &KeyId, position: Option<GridPosition>| {
+            keys.iter().position(|key_ref| {
+                &key_ref.key_id == key_id
+                    && position.is_none_or(|position| key_ref.grid_position == position)
+            })
+        };
+
+        let (list, field, index) = match kind {
+            DataErrorKind::UnknownKey(key_id) => ("keys", "key_id", key(key_id, None)?),
+            DataErrorKind::EmptySpan(key_id, position) => {
+                ("keys", "grid_position", key(key_id, Some(*position))?)
+            }
+            DataErrorKind::OutOfBounds {
+                key_id, position, ..
+            } => ("keys", "grid_position", key(key_id, Some(*position))?),
+            DataErrorKind::OverlappingSpan { key_id, .. } => {
+                ("keys", "grid_position", key(key_id, None)?)
+            }
+            // the second key in the cell is the one that clashes
+            DataErrorKind::DuplicatePosition(position) => (
+                "keys",
+                "grid_position",
+                keys.iter()
+                    .enumerate()
+                    .filter(|(_, key_ref)| key_ref.grid_position == *position)
+                    .nth(1)?
+                    .0,
+            ),
+            DataErrorKind::SpacerOutOfBounds { position, .. } => (
+                "spacers",
+                "grid_position",
+                keypad_view
+                    .spacers
+                    .iter()
+                    .position(|spacer_ref| spacer_ref.grid_position() == *position)?,
+            ),
+            // the last spacer over the cell is the one that found it taken
+            DataErrorKind::OverlappingSpacer(cell) => (
+                "spacers",
+                "grid_position",
+                keypad_view.spacers.iter().rposition(|spacer_ref| {
+                    let position = spacer_ref.grid_position();
+                    let span = spacer_ref.grid_span();
+                    (position.row..position.row.saturating_add(span.row_span)).contains(&cell.row)
+                        && (position.column..position.column.saturating_add(span.column_span))
+                            .contains(&cell.column)
+                })?,
+            ),
+            _ => return None,
+        };
+
+        self.find_in_list(&keypad_view.source.path, list, field, index)
+    }
+
+    // read and parse a file, reporting anything that goes wrong
+    fn parse<T: DeserializeOwned>(&mut self, source: &DataSource, id: impl ToString) -> Option<T> {
+        match self.read(source) {
+            Ok(value) => Some(value),
+            Err(error) => {
+                self.report.push(error.with_id(id));
+                None
+            }
+        }
+    }
+
+    // read and parse a file, keeping the text to find fields in later
+    fn read<T: DeserializeOwned>(&mut self, source: &DataSource) -> Result<T, DataError> {
+        let content = source
+            .read_to_string()
+            .map_err(|e| DataError::from(e).with_path(&source.path))?;
+        let value = ron::from_str(&content);
+        self.contents.insert(source.path.clone(), content);
+
+        value.map_err(|e| DataError::from(e).with_path(&source.path))
+    }
+
+    fn push(&mut self, error: DataError, span: Option<Span>) {
+        match span {
+            Some(span) if error.span.is_none() => self.report.push(error.with_span(span)),
+            _ => self.report.push(error),
+        }
+    }
+
+    fn find(&self, path: &Path, field: &str) -> Option<Span> {
+        find_field(self.contents.get(path)?, field)
+    }
+
+    fn find_in_list(&self, path: &Path, list: &str, field: &str, index: usize) -> Option<Span> {
+        list_fields(self.contents.get(path)?, list, field)
+            .into_iter()
+            .nth(index)
+    }
+}
+
+// the spans of a field inside one of a file's lists - from "list:" to the
+// ] that closes it, none of the refs in a list have lists of their own
+fn list_fields(content: &str, list: &str, field: &str) -> Vec<Span> {
+    let Some(open) = find_field(content, list) else {
+        return Vec::new();
+    };
+    let open = (open.end.line, open.end.col);
+
+    let mut close = None;
+    let (mut line, mut col) = (1, 1);
+    for c in content.chars() {
+        if c == ']' && (line, col) > open {
+            close = Some((line, col));
+            break;
+        }
+        match c {
+            '\n' => (line, col) = (line + 1, 1),
+            _ => col += 1,
+        }
+    }
+
+    find_fields(content, field)
+        .into_iter()
+        .filter(|span| {
+            let start = (span.start.line, span.start.col);
+            start > open && close.is_none_or(|close| start < close)
+        })
+        .collect()
+}
+
+fn on_disk(path: PathBuf) -> DataSource {
+    DataSource {
+        origin: DataOrigin::User,
+        path,
+    }
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
+
+fn ron_files(dir: &Path) -> Vec<PathBuf> {
+    let mut files: Vec<PathBuf> = dir_entries(dir)
+        .into_iter()
+        .filter(|path| path.is_file() && path.extension().is_some_and(|ext| ext == "ron"))
+        .collect();
+    files.sort();
+    files
+}
+
+fn sub_dirs(dir: &Path) -> Vec<PathBuf> {
+    let mut dirs: Vec<PathBuf> = dir_entries(dir)
+        .into_iter()
+        .filter(|path| path.is_dir())
+        .collect();
+    dirs.sort();
+    dirs
+}
+
+fn dir_entries(dir: &Path) -> Vec<PathBuf> {
+    fs::read_dir(dir)
+        .map(|entries| entries.flatten().map(|entry| entry.path()).collect())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // a data root in a scratch folder, removed again when the test is done
+    struct Root(PathBuf);
+
+    impl Root {
+        fn new(name: &str) -> Self {
+            Self(std::env::temp_dir().join(format!(
+                "extendcalc-lint-{}-{}",
+                std::process::id(),
+                name
+            )))
+        }
+
+        fn write(&self, relative_path: &str, content: &str) {
+            let path = self.0.join(relative_path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, content).unwrap();
+        }
+    }
+
+    impl Drop for Root {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    // what went wrong, in which file and where
+    fn problems(root: &Root) -> Vec<(DataErrorKind, String, Option<(usize, usize)>)> {
+        lint_root(&root.0)
+            .into_iter()
+            .map(|error| {
+                let file = error.path.as_deref().map(file_name).unwrap_or_default();
+                let start = error.span.map(|span| (span.start.line, span.start.col));
+                (error.kind, file, start)
+            })
+            .collect()
+    }
+
+    const ONE: &str =
+        "Key(\n    base: (id: \"one\", label: \"1\"),\n    key_type: Operand(value: 1),\n)\n";
+
+    #[test]
+    fn clean() {
+        let root = Root::new("clean");
+        root.write("key_definitions/test/one.ron", ONE);
+        root.write(
+            "keypad_definitions/pad.ron",
+            "Keypad(\n    id: \"pad\",\n    label: \"Pad\",\n    tooltip: \"\",\n    rows: 1,\n    columns: 2,\n    keys: [\n        KeyRef(key_id: (library: \"test\", id: \"one\"), grid_position: (row: 1, column: 1)),\n        KeyRef(key_id: (library: \"numbers\", id: \"two\"), grid_position: (row: 1, column: 2)),\n    ],\n)\n",
+        );
+        root.write(
+            "panel_definitions/main.ron",
+            "Panel(\n    id: \"main\",\n    label: \"Main\",\n    tooltip_text: \"\",\n    rows: 1,\n    columns: 2,\n    keypads: [\n        KeypadRef(id: \"pad\", row: 1, column: 1),\n        KeypadRef(id: \"memory\", row: 1, column: 2),\n    ],\n)\n",
+        );
+        root.write(
+            "panel_definitions/panel_list.ron",
+            "PanelList(\n    panel_refs: [\n        PanelRef(id: \"main\"),\n    ],\n)\n",
+        );
+
+        // numbers/two and the memory keypad come from the built-in data
+        assert!(problems(&root).is_empty(), "{:?}", problems(&root));
+    }
+
+    #[test]
+    fn every_problem_has_a_place() {
+        let root = Root::new("located");
+        root.write("key_definitions/stray.ron", ONE);
+        root.write(
+            "key_definitions/test/bad.ron",
+            "Key(\n    base: (id: \"bad\", label: \"b\"),\n    key_type: Operator(\n        qalc_term: \"{x} + {y}\",\n        arity: PostfixUnary,\n    ),\n)\n",
+        );
+        root.write(
+            "key_definitions/test/odd.ron",
+            "Key(\n    base: (id: \"even\", label: \"e\"),\n    key_type: Operand(value: 1),\n)\n",
+        );
+        root.write(
+            "keypad_definitions/pad.ron",
+            "Keypad(\n    id: \"pad\",\n    label: \"Pad\",\n    tooltip: \"\",\n    rows: 1,\n    columns: 2,\n    keys: [\n        KeyRef(key_id: (library: \"test\", id: \"bad\"), grid_position: (row: 1, column: 1)),\n        KeyRef(key_id: (library: \"test\", id: \"missing\"), grid_position: (row: 1, column: 2)),\n        KeyRef(key_id: (library: \"test\", id: \"bad\"), grid_position: (row: 1, column: 2)),\n        KeyRef(key_id: (library: \"test\", id: \"bad\"), grid_position: (row: 2, column: 1)),\n    ],\n)\n",
+        );
+        root.write(
+            "panel_definitions/main.ron",
+            "Panel(\n    id: \"main\",\n    label: \"Main\",\n    tooltip_text: \"\",\n    rows: 1,\n    columns: 2,\n    keypads: [\n        KeypadRef(id: \"pad\", row: 1, column: 1),\n        KeypadRef(id: \"nowhere\", row: 1, column: 2),\n        KeypadRef(id: \"memory\", row: 1, column: 3),\n    ],\n)\n",
+        );
+        root.write(
+            "panel_definitions/panel_list.ron",
+            "PanelList(\n    panel_refs: [\n        PanelRef(id: \"main\"),\n        PanelRef(id: \"gone\"),\n    ],\n)\n",
+        );
+
+        let problems = problems(&root);
+        let places: Vec<(&str, Option<(usize, usize)>)> = problems
+            .iter()
+            .map(|(_, file, start)| (file.as_str(), *start))
+            .collect();
+        assert_eq!(
+            places,
+            [
+                ("stray", None),
+                ("bad", Some((4, 9))),
+                ("odd", Some((2, 12))),
+                ("panel_list", Some((4, 18))),
+                ("main", Some((9, 19))),
+                ("main", Some((10, 33))),
+                ("pad", Some((9, 16))),
+                ("pad", Some((10, 54))),
+                ("pad", Some((11, 54))),
+                ("odd", Some((2, 12))),
+            ]
+        );
+
+        let kinds: Vec<&DataErrorKind> = problems.iter().map(|(kind, _, _)| kind).collect();
+        assert!(matches!(kinds[0], DataErrorKind::StrayFile));
+        assert!(matches!(kinds[1], DataErrorKind::Term(_)));
+        assert!(matches!(kinds[2], DataErrorKind::IdMismatch { .. }));
+        assert!(matches!(kinds[3], DataErrorKind::UnknownPanel(id) if id == "gone"));
+        assert!(matches!(kinds[4], DataErrorKind::UnknownKeypad(id) if id == "nowhere"));
+        assert!(matches!(kinds[5], DataErrorKind::KeypadOutOfBounds { .. }));
+        assert!(matches!(kinds[6], DataErrorKind::UnknownKey(_)));
+        assert!(matches!(kinds[7], DataErrorKind::DuplicatePosition(_)));
+        assert!(matches!(kinds[8], DataErrorKind::OutOfBounds { .. }));
+        assert!(matches!(kinds[9], DataErrorKind::UnusedKey(_)));
+    }
+
+    #[test]
+    fn fields_in_a_list() {
+        let content = "Keypad(\n    keys: [\n        KeyRef(grid_position: (row: 1, column: 1)),\n        KeyRef(grid_position: (row: 1, column: 2)),\n    ],\n    spacers: [\n        Spacer(grid_position: (row: 2, column: 1)),\n    ],\n)\n";
+        let lines = |list, field| -> Vec<usize> {
+            list_fields(content, list, field)
+                .into_iter()
+                .map(|span| span.start.line)
+                .collect()
+        };
+
+        assert_eq!(lines("keys", "grid_position"), [3, 4]);
+        assert_eq!(lines("spacers", "grid_position"), [7]);
+        assert_eq!(lines("keys", "row"), [3, 4]);
+        assert!(lines("keypads", "grid_position").is_empty());
+    }
+}
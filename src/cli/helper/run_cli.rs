@@ -0,0 +1,58 @@
+// run a headless command and turn the outcome into an exit code - results
+// go to stdout, problems to stderr
+
+use std::io;
+use std::io::Read;
+
+use crate::cli::CliCommand;
+use crate::cli::CliError;
+use crate::cli::USAGE;
+use crate::cli::helper::run_eval;
+use crate::cli::helper::run_export;
+use crate::cli::helper::run_validate;
+use crate::config::Config;
+
+pub fn run_cli(command: Result<CliCommand, CliError>) -> i32 {
+    let outcome = match command {
+        Ok(CliCommand::Gui) => return 0,
+        Ok(CliCommand::Help) => Ok(USAGE.to_string()),
+        Ok(CliCommand::Eval(tokens)) => {
+            let (_, config) = Config::load();
+            read_tokens(tokens).and_then(|tokens| run_eval(&tokens, &config))
+        }
+        Ok(CliCommand::Export { format, session }) => run_export(format, &session),
+        Ok(CliCommand::Validate { json, root }) => match run_validate(json, root) {
+            Ok(exit_code) => return exit_code,
+            Err(error) => Err(error),
+        },
+        Err(error) => Err(error),
+    };
+
+    match outcome {
+        Ok(output) => {
+            println!("{}", output);
+            0
+        }
+        Err(error) => {
+            eprintln!("extendcalc: {}", error);
+            if let CliError::Usage(_) = error {
+                eprintln!("\n{}", USAGE);
+            }
+            error.exit_code()
+        }
+    }
+}
+
+// no tokens on the command line means they're being piped in
+fn read_tokens(tokens: Vec<String>) -> Result<Vec<String>, CliError> {
+    if !tokens.is_empty() {
+        return Ok(tokens);
+    }
+
+    let mut input = String::new();
+    io::stdin()
+        .read_to_string(&mut input)
+        .map_err(|e| CliError::Usage(format!("failed to read stdin: {}", e)))?;
+
+    Ok(input.split_whitespace().map(str::to_string).collect())
+}
@@ -0,0 +1,105 @@
+// headless evaluation - every token is either a plain number, entered as
+// is, or a key id that is loaded from the data roots and pressed, exactly
+// as the GUI would. a pending operator is committed at the end so a
+// trailing internal/equals is optional
+//
+// the memory registers are the ones the GUI left behind, but nothing a
+// headless run puts in memory is kept
+
+use rust_decimal::Decimal;
+
+use crate::calc::CalcState;
+use crate::cli::CliError;
+use crate::config::Config;
+use crate::data::Key;
+use crate::data::KeyType;
+use crate::ui::KeyId;
+
+pub fn run_eval(tokens: &[String], config: &Config) -> Result<String, CliError> {
+    if tokens.is_empty() {
+        return Err(CliError::Usage("eval needs at least one token".to_string()));
+    }
+
+    let mut calc = CalcState::with_precision(config.precision).with_engine(config.engine.engine());
+    calc.set_memory(config.memory.clone());
+
+    for token in tokens {
+        match token.parse::<Decimal>() {
+            Ok(value) => calc.enter(value),
+            Err(_) => {
+                let key = Key::from_ron(&parse_key_id(token)?)?;
+                calc.press(key.key_type());
+            }
+        }
+
+        if let Some(error) = calc.error() {
+            return Err(error.clone().into());
+        }
+    }
+
+    if calc.has_pending() {
+        calc.press(&KeyType::Internal {
+            operation: "EQUALS".to_string(),
+            register: String::new(),
+        });
+    }
+
+    match calc.error() {
+        Some(error) => Err(error.clone().into()),
+        None => Ok(calc.display()),
+    }
+}
+
+fn parse_key_id(token: &str) -> Result<KeyId, CliError> {
+    match token.split_once('/') {
+        Some((library, id)) if !library.is_empty() && !id.is_empty() && !id.contains('/') => {
+            Ok(KeyId::new(library, id))
+        }
+        _ => Err(CliError::Usage(format!(
+            "'{}' is neither a number nor a key id (library/id)",
+            token
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // the keys come from the built-in library
+    fn eval(tokens: &[&str]) -> Result<String, i32> {
+        let tokens: Vec<String> = tokens.iter().map(|token| token.to_string()).collect();
+        run_eval(&tokens, &Config::default()).map_err(|error| error.exit_code())
+    }
+
+    #[test]
+    fn results() {
+        assert_eq!(eval(&["12"]), Ok("12".to_string()));
+        assert_eq!(eval(&["100", "business/plusGST"]), Ok("115".to_string()));
+        // the pending operator is committed without a trailing equals
+        assert_eq!(
+            eval(&["2", "scientific/power", "10"]),
+            Ok("1024".to_string())
+        );
+        assert_eq!(
+            eval(&["2", "scientific/power", "10", "internal/equals"]),
+            Ok("1024".to_string())
+        );
+    }
+
+    #[test]
+    fn exit_codes() {
+        let cases: [(&[&str], i32); 6] = [
+            (&[], CliError::USAGE_EXIT),
+            (&["twelve"], CliError::USAGE_EXIT),
+            (&["scientific/"], CliError::USAGE_EXIT),
+            (&["0", "scientific/reciprocal"], CliError::EVAL_EXIT),
+            (&["-1", "scientific/factorial"], CliError::EVAL_EXIT),
+            (&["1", "nowhere/nothing"], CliError::DATA_EXIT),
+        ];
+
+        for (tokens, exit_code) in cases {
+            assert_eq!(eval(tokens), Err(exit_code), "{:?}", tokens);
+        }
+    }
+}
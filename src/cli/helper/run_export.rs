@@ -0,0 +1,31 @@
+// print a saved history session in one of the export formats - keys are
+// shown by their labels, loaded from the data roots like eval does, and
+// numbers are written the way the system locale writes them
+
+use crate::cli::CliError;
+use crate::data::Key;
+use crate::editor::helper::valid_id;
+use crate::history::ExportFormat;
+use crate::history::NumberFormat;
+use crate::history::Session;
+use crate::history::helper::export_history;
+
+pub fn run_export(format: ExportFormat, session: &str) -> Result<String, CliError> {
+    // the name becomes part of a path, so nothing that could climb out
+    if !valid_id(session) {
+        return Err(CliError::Usage(format!(
+            "'{}' isn't a session name - they only use letters, digits, '_' and '-'",
+            session
+        )));
+    }
+    let session = Session::from_ron(session)?;
+
+    let output = export_history(&session.tape, format, &NumberFormat::system(), |key_id| {
+        Key::from_ron(key_id)
+            .map(|key| key.label)
+            .unwrap_or_else(|_| key_id.to_string())
+    });
+
+    // println adds the last newline back
+    Ok(output.trim_end().to_string())
+}
@@ -0,0 +1,38 @@
+// the library linter - lint one data root and print the report, either
+// for people (file:line:column per problem) or as JSON for CI. only
+// errors fail the run, warnings are just reported
+
+use std::path::PathBuf;
+
+use crate::cli::CliError;
+use crate::cli::LintReport;
+use crate::cli::helper::lint_root;
+use crate::data::DataError;
+use crate::data::DataRoot;
+
+pub fn run_validate(json: bool, root: Option<PathBuf>) -> Result<i32, CliError> {
+    let root = root
+        .or_else(|| DataRoot::user().map(|root| root.path.clone()))
+        .ok_or_else(|| CliError::Usage("no data root given and no user data root".to_string()))?;
+
+    if !root.is_dir() {
+        let error = std::io::Error::from(std::io::ErrorKind::NotFound);
+        return Err(DataError::from(error).with_path(&root).into());
+    }
+
+    let report = LintReport::new(root.clone(), &lint_root(&root));
+
+    match json {
+        true => {
+            let output = serde_json::to_string_pretty(&report)
+                .map_err(|e| DataError::from(std::io::Error::from(e)))?;
+            println!("{}", output);
+        }
+        false => println!("{}", report),
+    }
+
+    Ok(match report.is_clean() {
+        true => 0,
+        false => CliError::DATA_EXIT,
+    })
+}
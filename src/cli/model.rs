@@ -0,0 +1,12 @@
+mod cli_command;
+mod cli_error;
+mod lint_problem;
+mod lint_report;
+mod severity;
+
+pub use cli_command::CliCommand;
+pub use cli_command::USAGE;
+pub use cli_error::CliError;
+pub use lint_problem::LintProblem;
+pub use lint_report::LintReport;
+pub use severity::Severity;
@@ -0,0 +1,180 @@
+// what we were asked to do on the command line - no subcommand means
+// open the calculator window as usual
+//
+//   extendcalc                       the GUI
+//   extendcalc eval [TOKEN...]       headless evaluation, tokens from
+//                                    stdin if there are none
+//   extendcalc validate [--json] [DIR]   lint a data root
+//   extendcalc export FORMAT SESSION     print a saved session
+
+use std::path::PathBuf;
+
+use crate::cli::CliError;
+use crate::history::ExportFormat;
+
+pub const USAGE: &str = "\
+usage: extendcalc [COMMAND]
+
+commands:
+  eval [TOKEN...]   press a sequence of keys and print the result
+                    a token is a key id (library/id) or a plain number,
+                    tokens are read from stdin when none are given
+  validate [--json] [DIR]
+                    check every definition under a data root (the user
+                    data root by default), --json for machine output
+  export FORMAT SESSION
+                    print a saved history session as csv, md (Markdown)
+                    or json
+  help              show this message
+
+exit codes:
+  0  success
+  1  evaluation error
+  2  usage error
+  3  data error (for validate, any lint errors)";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CliCommand {
+    Gui,
+    Eval(Vec<String>),
+    Validate {
+        json: bool,
+        root: Option<PathBuf>,
+    },
+    Export {
+        format: ExportFormat,
+        session: String,
+    },
+    Help,
+}
+
+impl CliCommand {
+    pub fn from_args(mut args: impl Iterator<Item = String>) -> Result<Self, CliError> {
+        let Some(command) = args.next() else {
+            return Ok(Self::Gui);
+        };
+
+        match command.as_str() {
+            "eval" => Ok(Self::Eval(args.collect())),
+            "validate" => {
+                let mut json = false;
+                let mut root = None;
+                for arg in args {
+                    match arg.as_str() {
+                        "--json" => json = true,
+                        _ if arg.starts_with('-') => {
+                            return Err(CliError::Usage(format!("unknown option '{}'", arg)));
+                        }
+                        _ if root.is_none() => root = Some(PathBuf::from(arg)),
+                        _ => {
+                            return Err(CliError::Usage(
+                                "validate takes one data root".to_string(),
+                            ));
+                        }
+                    }
+                }
+                Ok(Self::Validate { json, root })
+            }
+            "export" => {
+                let (Some(format), Some(session), None) = (args.next(), args.next(), args.next())
+                else {
+                    return Err(CliError::Usage(
+                        "export takes a format and a session name".to_string(),
+                    ));
+                };
+                let format = ExportFormat::from_name(&format).ok_or_else(|| {
+                    CliError::Usage(format!("unknown export format '{}'", format))
+                })?;
+                Ok(Self::Export { format, session })
+            }
+            "help" | "--help" | "-h" => Ok(Self::Help),
+            _ => Err(CliError::Usage(format!("unknown command '{}'", command))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn from_args(args: &[&str]) -> Result<CliCommand, CliError> {
+        CliCommand::from_args(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn commands() {
+        let cases = [
+            (vec![], CliCommand::Gui),
+            (vec!["help"], CliCommand::Help),
+            (vec!["--help"], CliCommand::Help),
+            (vec!["-h"], CliCommand::Help),
+            (vec!["eval"], CliCommand::Eval(vec![])),
+            (
+                vec!["eval", "2", "scientific/power", "10"],
+                CliCommand::Eval(vec![
+                    "2".to_string(),
+                    "scientific/power".to_string(),
+                    "10".to_string(),
+                ]),
+            ),
+            (
+                vec!["validate"],
+                CliCommand::Validate {
+                    json: false,
+                    root: None,
+                },
+            ),
+            (
+                vec!["validate", "--json", "data"],
+                CliCommand::Validate {
+                    json: true,
+                    root: Some(PathBuf::from("data")),
+                },
+            ),
+            (
+                vec!["validate", "data", "--json"],
+                CliCommand::Validate {
+                    json: true,
+                    root: Some(PathBuf::from("data")),
+                },
+            ),
+            (
+                vec!["export", "md", "work"],
+                CliCommand::Export {
+                    format: ExportFormat::Markdown,
+                    session: "work".to_string(),
+                },
+            ),
+            (
+                vec!["export", "JSON", "work"],
+                CliCommand::Export {
+                    format: ExportFormat::Json,
+                    session: "work".to_string(),
+                },
+            ),
+        ];
+
+        for (args, expected) in cases {
+            assert_eq!(from_args(&args).unwrap(), expected, "{:?}", args);
+        }
+    }
+
+    #[test]
+    fn usage_errors() {
+        let cases: [&[&str]; 8] = [
+            &["calculate"],
+            &["/home/me/notes.txt"],
+            &["validate", "--yaml"],
+            &["validate", "one", "two"],
+            &["export"],
+            &["export", "csv"],
+            &["export", "csv", "work", "extra"],
+            &["export", "xlsx", "work"],
+        ];
+
+        for args in cases {
+            let error = from_args(args).unwrap_err();
+            assert_eq!(error.exit_code(), CliError::USAGE_EXIT, "{:?}", args);
+        }
+    }
+}
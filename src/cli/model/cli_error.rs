@@ -0,0 +1,45 @@
+// everything the command line can fail with - each kind has its own exit
+// code so scripts can tell a bad calculation from a broken library
+
+use thiserror::Error;
+
+use crate::calc::CalcError;
+use crate::data::DataError;
+
+#[derive(Debug, Clone, Error)]
+pub enum CliError {
+    #[error("{0}")]
+    Usage(String),
+
+    #[error("{0}")]
+    Data(DataError),
+
+    #[error("{0}")]
+    Eval(CalcError),
+}
+
+impl CliError {
+    pub const EVAL_EXIT: i32 = 1;
+    pub const USAGE_EXIT: i32 = 2;
+    pub const DATA_EXIT: i32 = 3;
+
+    pub const fn exit_code(&self) -> i32 {
+        match self {
+            Self::Eval(_) => Self::EVAL_EXIT,
+            Self::Usage(_) => Self::USAGE_EXIT,
+            Self::Data(_) => Self::DATA_EXIT,
+        }
+    }
+}
+
+impl From<DataError> for CliError {
+    fn from(error: DataError) -> Self {
+        Self::Data(error)
+    }
+}
+
+impl From<CalcError> for CliError {
+    fn from(error: CalcError) -> Self {
+        Self::Eval(error)
+    }
+}
@@ -0,0 +1,48 @@
+// one problem found by the linter, flattened out of the DataError so it
+// prints as file:line:column and serialises to plain JSON
+
+use serde::Serialize;
+use std::path::PathBuf;
+
+use crate::cli::Severity;
+use crate::data::DataError;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LintProblem {
+    pub severity: Severity,
+    pub message: String,
+    pub file: Option<PathBuf>,
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+    pub id: Option<String>,
+}
+
+impl From<&DataError> for LintProblem {
+    fn from(error: &DataError) -> Self {
+        Self {
+            severity: Severity::of(&error.kind),
+            message: error.kind.to_string(),
+            file: error.path.clone(),
+            line: error.span.as_ref().map(|span| span.start.line),
+            column: error.span.as_ref().map(|span| span.start.col),
+            id: error.id.clone(),
+        }
+    }
+}
+
+impl std::fmt::Display for LintProblem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(file) = &self.file {
+            write!(f, "{}", file.display())?;
+            if let (Some(line), Some(column)) = (self.line, self.column) {
+                write!(f, ":{}:{}", line, column)?;
+            }
+            write!(f, ": ")?;
+        }
+        write!(f, "{}: {}", self.severity, self.message)?;
+        if let Some(id) = &self.id {
+            write!(f, " (loading '{}')", id)?;
+        }
+        Ok(())
+    }
+}
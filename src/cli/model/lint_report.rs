@@ -0,0 +1,53 @@
+// everything the linter found under one data root, with the totals - this
+// is what gets printed, either line by line or as JSON
+
+use serde::Serialize;
+use std::path::PathBuf;
+
+use crate::cli::LintProblem;
+use crate::cli::Severity;
+use crate::data::ValidationReport;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LintReport {
+    pub root: PathBuf,
+    pub errors: usize,
+    pub warnings: usize,
+    pub problems: Vec<LintProblem>,
+}
+
+impl LintReport {
+    pub fn new(root: PathBuf, report: &ValidationReport) -> Self {
+        let problems: Vec<LintProblem> = report.into_iter().map(LintProblem::from).collect();
+        let errors = problems
+            .iter()
+            .filter(|problem| problem.severity == Severity::Error)
+            .count();
+
+        Self {
+            root,
+            errors,
+            warnings: problems.len() - errors,
+            problems,
+        }
+    }
+
+    pub fn is_clean(&self) -> bool {
+        self.errors == 0
+    }
+}
+
+impl std::fmt::Display for LintReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for problem in &self.problems {
+            writeln!(f, "{}", problem)?;
+        }
+        write!(
+            f,
+            "{}: {} errors, {} warnings",
+            self.root.display(),
+            self.errors,
+            self.warnings
+        )
+    }
+}
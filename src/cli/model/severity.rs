@@ -0,0 +1,35 @@
+// how bad a lint problem is - only errors fail the run, warnings are
+// things that work but are probably a mistake
+
+use serde::Serialize;
+
+use crate::data::DataErrorKind;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+impl Severity {
+    pub fn of(kind: &DataErrorKind) -> Self {
+        match kind {
+            DataErrorKind::UnusedKey(_) | DataErrorKind::StrayFile => Self::Warning,
+            _ => Self::Error,
+        }
+    }
+
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Error => "error",
+            Self::Warning => "warning",
+        }
+    }
+}
+
+impl std::fmt::Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
@@ -2,8 +2,48 @@
 
 use cosmic::cosmic_config::{self, CosmicConfigEntry, cosmic_config_derive::CosmicConfigEntry};
 
-#[derive(Debug, Default, Clone, CosmicConfigEntry, Eq, PartialEq)]
+use crate::calc::EngineKind;
+use crate::calc::Memory;
+
+pub const APP_ID: &str = "com.github.foodmonkey.extendcalc";
+
+#[derive(Debug, Clone, CosmicConfigEntry, Eq, PartialEq)]
 #[version = 1]
 pub struct Config {
-    demo: String,
+    // number of decimal places results are rounded to
+    pub precision: u32,
+    // the engine that works out the expressions
+    pub engine: EngineKind,
+    // the session that was open last, restored at startup
+    pub last_session: Option<String>,
+    // what's in the memory registers, kept between runs
+    pub memory: Memory,
+}
+
+impl Config {
+    // load the config - falling back to the defaults for anything missing.
+    // the handler is only there if the config store could be opened
+    pub fn load() -> (Option<cosmic_config::Config>, Self) {
+        let config_handler = cosmic_config::Config::new(APP_ID, Self::VERSION).ok();
+        let config = config_handler
+            .as_ref()
+            .map(|context| match Self::get_entry(context) {
+                Ok(config) => config,
+                Err((_errors, config)) => config,
+            })
+            .unwrap_or_default();
+
+        (config_handler, config)
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            precision: 10,
+            engine: EngineKind::default(),
+            last_session: None,
+            memory: Memory::default(),
+        }
+    }
 }
@@ -1,16 +1,38 @@
 pub mod helper;
 mod model;
 
+pub(crate) use model::AssembledLibrary;
+pub(crate) use model::CacheEntry;
+pub(crate) use model::DATA_ROOTS;
 pub(crate) use model::DataDir;
 pub(crate) use model::DataError;
+pub(crate) use model::DataErrorKind;
+pub(crate) use model::DataOrigin;
+pub(crate) use model::DataRoot;
+pub(crate) use model::DataSource;
+pub(crate) use model::EmbeddedData;
 
 pub(crate) use model::Key;
+pub(crate) use model::KeyBase;
+pub(crate) use model::KeyList;
 pub(crate) use model::KeyRef;
+pub(crate) use model::KeyType;
 
 pub(crate) use model::Keypad;
 pub(crate) use model::KeypadList;
 pub(crate) use model::KeypadRef;
 
+pub(crate) use model::Cached;
+pub(crate) use model::LIBRARY_CACHE;
+pub(crate) use model::LibraryCache;
+pub(crate) use model::LibraryChange;
+pub(crate) use model::LibraryReload;
+pub(crate) use model::SourceStamp;
+
 pub(crate) use model::Panel;
 pub(crate) use model::PanelList;
 pub(crate) use model::PanelRef;
+
+pub(crate) use model::SpacerRef;
+
+pub(crate) use model::ValidationReport;
@@ -1,12 +1,35 @@
+mod find_field;
+mod find_fields;
+mod find_source;
+mod list_key_ids;
 mod load_and_parse;
+mod load_cached;
+mod load_cached_library;
+mod load_key;
+mod load_keygrids;
 mod load_keypad;
 mod load_keypads;
 mod load_panel;
 mod load_panel_list;
 mod load_panels;
 mod path_builder;
+mod reload_library;
+mod remove_ron;
+mod save_library_cache;
+mod save_ron;
+mod watch_library;
 
+pub(crate) use find_field::find_field;
+pub(crate) use find_fields::find_fields;
+pub(crate) use find_source::find_source;
+pub(crate) use find_source::find_source_in;
+pub(crate) use list_key_ids::list_key_ids;
 pub(crate) use load_and_parse::load_and_parse;
+pub(crate) use load_cached::load_cached;
+pub(crate) use load_cached_library::load_cached_library;
+
+pub(crate) use load_key::load_key;
+pub(crate) use load_keygrids::load_keygrids;
 
 pub(crate) use load_keypad::load_keypad;
 pub(crate) use load_keypads::load_keypads;
@@ -15,3 +38,8 @@ pub(crate) use load_panel::load_panel;
 pub(crate) use load_panel_list::load_panel_list;
 pub(crate) use load_panels::load_panels;
 pub(crate) use path_builder::path_builder;
+pub(crate) use reload_library::reload_library;
+pub(crate) use remove_ron::remove_ron;
+pub(crate) use save_library_cache::save_library_cache;
+pub(crate) use save_ron::save_ron;
+pub(crate) use watch_library::watch_library;
@@ -0,0 +1,11 @@
+// find where a field is set in a RON file - serde doesn't give us spans
+// for values that parse fine but are wrong, so we look for "field:" the
+// first time it turns up as a whole word
+
+use ron::error::Span;
+
+use crate::data::helper::find_fields;
+
+pub fn find_field(content: &str, field: &str) -> Option<Span> {
+    find_fields(content, field).into_iter().next()
+}
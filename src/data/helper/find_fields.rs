@@ -0,0 +1,58 @@
+// every place a field is set in a RON file, in the order they turn up -
+// the nth KeyRef's key_id is the nth span, which is how a problem with
+// one ref in a list gets pointed at the right line
+
+use ron::error::Position;
+use ron::error::Span;
+
+pub fn find_fields(content: &str, field: &str) -> Vec<Span> {
+    let mut spans = Vec::new();
+
+    for (line_index, line) in content.lines().enumerate() {
+        for (byte_index, _) in line.match_indices(field) {
+            let before = line[..byte_index].chars().next_back();
+            let after = line[byte_index + field.len()..].trim_start();
+
+            let whole_word = !before.is_some_and(|c| c.is_alphanumeric() || c == '_');
+            if whole_word && after.starts_with(':') {
+                let col = line[..byte_index].chars().count() + 1;
+                let start = Position {
+                    line: line_index + 1,
+                    col,
+                };
+                let end = Position {
+                    line: line_index + 1,
+                    col: col + field.chars().count(),
+                };
+                spans.push(Span { start, end });
+            }
+        }
+    }
+
+    spans
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEYPAD: &str = "Keypad(\n    id: \"pad\",\n    keys: [\n        KeyRef(key_id: (library: \"a\", id: \"one\")),\n        KeyRef(\n            key_id: (library: \"a\", id: \"two\"),\n        ),\n    ],\n)\n";
+
+    fn starts(spans: Vec<Span>) -> Vec<(usize, usize)> {
+        spans
+            .into_iter()
+            .map(|span| (span.start.line, span.start.col))
+            .collect()
+    }
+
+    #[test]
+    fn in_order() {
+        assert_eq!(starts(find_fields(KEYPAD, "key_id")), [(4, 16), (6, 13)]);
+        assert_eq!(
+            starts(find_fields(KEYPAD, "id")),
+            [(2, 5), (4, 39), (6, 36)]
+        );
+        assert_eq!(starts(find_fields(KEYPAD, "library")), [(4, 25), (6, 22)]);
+        assert!(find_fields(KEYPAD, "grid_position").is_empty());
+    }
+}
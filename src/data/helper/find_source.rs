@@ -0,0 +1,115 @@
+// search the data roots in order for a definition file - the first root
+// that has it wins, which is how user files shadow system and built-in ones
+
+use std::io;
+use std::path::Path;
+
+use crate::data::DATA_ROOTS;
+use crate::data::DataError;
+use crate::data::DataRoot;
+use crate::data::DataSource;
+
+pub fn find_source(relative_path: &Path) -> Result<DataSource, DataError> {
+    find_source_in(&DATA_ROOTS, relative_path)
+}
+
+// the same search over any list of roots
+pub fn find_source_in(roots: &[DataRoot], relative_path: &Path) -> Result<DataSource, DataError> {
+    roots
+        .iter()
+        .map(|root| DataSource {
+            origin: root.origin,
+            path: root.path.join(relative_path),
+        })
+        .find(|source| source.exists())
+        .ok_or_else(|| {
+            DataError::from(io::Error::from(io::ErrorKind::NotFound)).with_path(relative_path)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::DataDir;
+    use crate::data::DataOrigin;
+    use std::fs;
+    use std::path::PathBuf;
+
+    // a user and a system root in a scratch folder, ahead of the built-in one
+    struct Roots {
+        scratch: PathBuf,
+        roots: Vec<DataRoot>,
+    }
+
+    impl Roots {
+        fn new(name: &str) -> Self {
+            let scratch = std::env::temp_dir().join(format!(
+                "extendcalc-roots-{}-{}",
+                std::process::id(),
+                name
+            ));
+            let roots = vec![
+                DataRoot {
+                    origin: DataOrigin::User,
+                    path: scratch.join("user"),
+                },
+                DataRoot {
+                    origin: DataOrigin::System,
+                    path: scratch.join("system"),
+                },
+                DataRoot {
+                    origin: DataOrigin::BuiltIn,
+                    path: PathBuf::from(DataDir::Data),
+                },
+            ];
+            Self { scratch, roots }
+        }
+
+        fn write(&self, index: usize, relative_path: &Path) {
+            let path = self.roots[index].path.join(relative_path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, "").unwrap();
+        }
+
+        fn origin(&self, relative_path: &Path) -> Option<DataOrigin> {
+            find_source_in(&self.roots, relative_path)
+                .ok()
+                .map(|source| source.origin)
+        }
+    }
+
+    impl Drop for Roots {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.scratch);
+        }
+    }
+
+    #[test]
+    fn user_shadows_system_shadows_built_in() {
+        let roots = Roots::new("shadow");
+        let square = Path::new("key_definitions/scientific/square.ron");
+        assert_eq!(roots.origin(square), Some(DataOrigin::BuiltIn));
+
+        roots.write(1, square);
+        assert_eq!(roots.origin(square), Some(DataOrigin::System));
+
+        roots.write(0, square);
+        let source = find_source_in(&roots.roots, square).unwrap();
+        assert_eq!(source.origin, DataOrigin::User);
+        assert_eq!(source.path, roots.roots[0].path.join(square));
+
+        // a user file shadows a system one even with nothing built in
+        let own = Path::new("key_definitions/mine/own.ron");
+        roots.write(1, own);
+        roots.write(0, own);
+        assert_eq!(roots.origin(own), Some(DataOrigin::User));
+    }
+
+    #[test]
+    fn nowhere() {
+        let roots = Roots::new("nowhere");
+        let missing = Path::new("key_definitions/mine/missing.ron");
+        let error = find_source_in(&roots.roots, missing).unwrap_err();
+        assert_eq!(error.path.as_deref(), Some(missing));
+    }
+}
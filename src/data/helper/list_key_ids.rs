@@ -0,0 +1,75 @@
+// every key id in every data root - the editors offer these to pick from.
+// a key shadowed in a higher root is still only listed once
+
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+
+use crate::data::DATA_ROOTS;
+use crate::data::DataDir;
+use crate::data::DataOrigin;
+use crate::data::EmbeddedData;
+use crate::ui::KeyId;
+
+pub fn list_key_ids() -> Vec<KeyId> {
+    let mut seen: HashSet<KeyId> = HashSet::new();
+    let mut key_ids: Vec<KeyId> = Vec::new();
+
+    for root in DATA_ROOTS.iter() {
+        let found = match root.origin {
+            DataOrigin::BuiltIn => embedded_key_ids(),
+            _ => disk_key_ids(&root.path.join(DataDir::KeyDefinitions)),
+        };
+
+        for key_id in found {
+            if seen.insert(key_id.clone()) {
+                key_ids.push(key_id);
+            }
+        }
+    }
+
+    key_ids.sort_by(|a, b| (&a.library, &a.id).cmp(&(&b.library, &b.id)));
+    key_ids
+}
+
+// key_definitions/<library>/<id>.ron inside the binary
+fn embedded_key_ids() -> Vec<KeyId> {
+    EmbeddedData::iter()
+        .filter_map(|file| {
+            let parts: Vec<&str> = file.split('/').collect();
+            match parts.as_slice() {
+                [folder, library, name] if *folder == DataDir::KeyDefinitions.as_str() => {
+                    name.strip_suffix(".ron").map(|id| KeyId::new(*library, id))
+                }
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+fn disk_key_ids(key_dir: &Path) -> Vec<KeyId> {
+    let Ok(libraries) = fs::read_dir(key_dir) else {
+        return Vec::new();
+    };
+
+    libraries
+        .flatten()
+        .filter(|library| library.path().is_dir())
+        .flat_map(|library| {
+            let name = library.file_name().to_string_lossy().into_owned();
+            fs::read_dir(library.path())
+                .into_iter()
+                .flatten()
+                .flatten()
+                .filter_map(move |file| {
+                    let path = file.path();
+                    match path.extension().is_some_and(|ext| ext == "ron") {
+                        true => path
+                            .file_stem()
+                            .map(|id| KeyId::new(name.clone(), id.to_string_lossy())),
+                        false => None,
+                    }
+                })
+        })
+        .collect()
+}
@@ -1,14 +1,16 @@
 // load and parse any RON structure - the centralised helper
 
 use serde::de::DeserializeOwned;
-use std::fs;
-use std::path::Path;
 
 use crate::data::DataError;
+use crate::data::DataSource;
 
-pub fn load_and_parse<T: DeserializeOwned>(path: &Path) -> Result<T, DataError> {
-    let content = fs::read_to_string(path)?; // becomes DataError::Io
-    let data: T = ron::from_str(&content)?; // becomes DataError::Ron
+pub fn load_and_parse<T: DeserializeOwned>(source: &DataSource) -> Result<T, DataError> {
+    let content = source
+        .read_to_string()
+        .map_err(|e| DataError::from(e).with_path(&source.path))?;
+    let data: T =
+        ron::from_str(&content).map_err(|e| DataError::from(e).with_path(&source.path))?;
 
     Ok(data)
 }
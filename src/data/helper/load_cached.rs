@@ -0,0 +1,41 @@
+// load a RON structure through the library cache - if the file's stamp
+// still matches the cached one we skip the read and parse altogether,
+// otherwise we parse it and refresh that one entry
+
+use serde::de::DeserializeOwned;
+
+use crate::data::CacheEntry;
+use crate::data::Cached;
+use crate::data::DataError;
+use crate::data::DataSource;
+use crate::data::LIBRARY_CACHE;
+use crate::data::SourceStamp;
+use crate::data::helper::load_and_parse;
+
+pub fn load_cached<T: Cached + DeserializeOwned>(source: &DataSource) -> Result<T, DataError> {
+    // stamp before reading - if the file changes underneath us the next
+    // start will see a newer stamp and parse it again
+    let stamp = SourceStamp::of(source);
+
+    if let Some(stamp) = stamp
+        && let Ok(mut cache) = LIBRARY_CACHE.lock()
+        && let Some(entry) = cache.get::<T>(source)
+        && entry.stamp == stamp
+    {
+        return Ok(entry.value.clone());
+    }
+
+    let value = load_and_parse::<T>(source)?;
+
+    if let Some(stamp) = stamp
+        && let Ok(mut cache) = LIBRARY_CACHE.lock()
+    {
+        let entry = CacheEntry {
+            stamp,
+            value: value.clone(),
+        };
+        cache.insert(source, entry);
+    }
+
+    Ok(value)
+}
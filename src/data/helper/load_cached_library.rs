@@ -0,0 +1,18 @@
+// the first thing a start does - take the assembled library from the cache
+// if none of its files have changed, otherwise it's loaded file by file
+
+use cosmic::app::Task;
+
+use crate::app::Message;
+use crate::data::LIBRARY_CACHE;
+
+pub fn load_cached_library() -> Task<Message> {
+    Task::future(async move {
+        let library = LIBRARY_CACHE
+            .lock()
+            .ok()
+            .and_then(|cache| cache.library())
+            .map(Box::new);
+        cosmic::action::app(Message::CachedLibraryLoaded(library))
+    })
+}
@@ -0,0 +1,13 @@
+// load the key from the RON file
+use crate::app::Message;
+use crate::data::Key;
+use crate::ui::KeyId;
+use cosmic::app::Task;
+
+pub fn load_key(key_id: KeyId, count: usize) -> Task<Message> {
+    // load the key and create the future key loaded task
+    Task::future(async move {
+        let result = Key::from_ron(&key_id);
+        cosmic::action::app(Message::KeyLoaded(result, key_id, count))
+    })
+}
@@ -0,0 +1,25 @@
+// handler for keypads loaded - we spawn the batch of key load tasks,
+// one per unique key, the keygrids get filled in once they're all back
+
+use cosmic::app::Task;
+
+use crate::app::Message;
+use crate::data::KeyList;
+
+pub fn load_keygrids(key_list: KeyList) -> Task<Message> {
+    // nothing to load means nothing will ever count down
+    if key_list.is_empty() {
+        return Task::done(cosmic::action::app(Message::KeyGridsLoaded));
+    }
+
+    let mut task_batch = Vec::new();
+    let count = key_list.len();
+
+    for key_id in key_list {
+        task_batch.push(Task::future(async move {
+            cosmic::action::app(Message::LoadKey(key_id, count))
+        }));
+    }
+
+    Task::batch(task_batch)
+}
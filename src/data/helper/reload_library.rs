@@ -0,0 +1,15 @@
+// re-run one changed definition (and what it references) through the
+// loaders - the cache stamps make sure only the changed files get parsed
+
+use cosmic::app::Task;
+
+use crate::app::Message;
+use crate::data::LibraryChange;
+use crate::data::LibraryReload;
+
+pub fn reload_library(change: LibraryChange) -> Task<Message> {
+    Task::future(async move {
+        let result = LibraryReload::load(&change);
+        cosmic::action::app(Message::LibraryReloaded(result))
+    })
+}
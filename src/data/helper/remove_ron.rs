@@ -0,0 +1,22 @@
+// remove a definition from the user data root - the other roots are read
+// only, so whatever they have with the same id shows through again
+
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::data::DataError;
+use crate::data::DataRoot;
+
+pub fn remove_ron(relative_path: &Path) -> Result<(), DataError> {
+    let Some(root) = DataRoot::user() else {
+        return Ok(());
+    };
+    let path = root.path.join(relative_path);
+
+    match fs::remove_file(&path) {
+        Ok(()) => Ok(()),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(DataError::from(e).with_path(&path)),
+    }
+}
@@ -0,0 +1,30 @@
+// write the library cache back out once the library has loaded cleanly,
+// along with the library it was assembled into
+
+use cosmic::app::Task;
+
+use crate::app::Message;
+use crate::data::LIBRARY_CACHE;
+use crate::ui::KeyGrids;
+use crate::ui::Keypads;
+use crate::ui::Keys;
+use crate::ui::Panels;
+
+pub fn save_library_cache(
+    panels: Panels,
+    keypads: Keypads,
+    keys: Keys,
+    keygrids: KeyGrids,
+) -> Task<Message> {
+    Task::future(async move {
+        let result = match LIBRARY_CACHE.lock() {
+            Ok(mut cache) => {
+                cache.store_library(panels, keypads, keys, keygrids);
+                cache.save()
+            }
+            // a poisoned cache isn't worth keeping - next start rebuilds it
+            Err(_) => Ok(()),
+        };
+        cosmic::action::app(Message::LibraryCacheSaved(result))
+    })
+}
@@ -0,0 +1,34 @@
+// write a definition into the user data root - that's the only root we
+// ever write to, and being first in the lookup it shadows anything else
+// with the same id
+
+use ron::ser::PrettyConfig;
+use serde::Serialize;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+
+use crate::data::DataError;
+use crate::data::DataErrorKind;
+use crate::data::DataRoot;
+
+pub fn save_ron<T: Serialize>(relative_path: &Path, value: &T) -> Result<PathBuf, DataError> {
+    let Some(root) = DataRoot::user() else {
+        return Err(
+            DataError::from(io::Error::from(io::ErrorKind::NotFound)).with_path(relative_path)
+        );
+    };
+    let path = root.path.join(relative_path);
+
+    let pretty = PrettyConfig::default().struct_names(true);
+    let content = ron::ser::to_string_pretty(value, pretty)
+        .map_err(|e| DataError::new(DataErrorKind::Ron(e)).with_path(&path))?;
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|e| DataError::from(e).with_path(dir))?;
+    }
+    fs::write(&path, content + "\n").map_err(|e| DataError::from(e).with_path(&path))?;
+
+    Ok(path)
+}
@@ -0,0 +1,98 @@
+// watch the on-disk data roots with inotify (via notify) and turn every
+// change to a definition file into a LibraryChanged message
+//
+// the built-in root is inside the binary so there's nothing to watch, and
+// the user root is created up front so new files there get picked up too
+//
+// one save in an editor is usually several events, so the changes are
+// held until the events have stopped for a moment and each file is only
+// reported once
+
+use cosmic::iced::Subscription;
+use cosmic::iced::futures::channel::mpsc;
+use cosmic::iced::futures::{SinkExt, Stream, StreamExt};
+use cosmic::iced::stream;
+use notify::{EventKind, RecursiveMode, Watcher};
+use std::fs;
+use std::time::Duration;
+
+use crate::app::Message;
+use crate::data::DATA_ROOTS;
+use crate::data::DataOrigin;
+use crate::data::LibraryChange;
+
+const SETTLE: Duration = Duration::from_millis(250);
+
+pub fn watch_library() -> Subscription<Message> {
+    Subscription::run(library_events)
+}
+
+fn library_events() -> impl Stream<Item = Message> {
+    stream::channel(100, |mut output: mpsc::Sender<Message>| async move {
+        let (sender, mut events) = mpsc::unbounded();
+
+        let Ok(mut watcher) =
+            notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+                let _ = sender.unbounded_send(event);
+            })
+        else {
+            eprintln!("failed to start the library watcher");
+            return;
+        };
+
+        for root in DATA_ROOTS.iter() {
+            match root.origin {
+                DataOrigin::BuiltIn => continue,
+                DataOrigin::User => {
+                    let _ = fs::create_dir_all(&root.path);
+                }
+                DataOrigin::System => {}
+            }
+
+            if root.path.is_dir()
+                && let Err(error) = watcher.watch(&root.path, RecursiveMode::Recursive)
+            {
+                eprintln!("failed to watch {}: {}", root.path.display(), error);
+            }
+        }
+
+        let mut pending: Vec<LibraryChange> = Vec::new();
+        loop {
+            let event = match pending.is_empty() {
+                true => events.next().await,
+                false => match tokio::time::timeout(SETTLE, events.next()).await {
+                    Ok(event) => event,
+                    Err(_) => {
+                        for change in pending.drain(..) {
+                            let _ = output.send(Message::LibraryChanged(change)).await;
+                        }
+                        continue;
+                    }
+                },
+            };
+            let Some(event) = event else {
+                break;
+            };
+            let Ok(event) = event else {
+                continue;
+            };
+
+            if !matches!(
+                event.kind,
+                EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)
+            ) {
+                continue;
+            }
+
+            for change in event
+                .paths
+                .iter()
+                .filter_map(|path| LibraryChange::from_path(path))
+            {
+                if !pending.contains(&change) {
+                    pending.push(change);
+                }
+            }
+        }
+    })
+}
@@ -1,27 +1,66 @@
+mod assembled_library;
+mod cache_entry;
 mod data_dir;
 mod data_error;
+mod data_error_kind;
+mod data_origin;
+mod data_root;
+mod data_source;
+mod embedded_data;
 
 mod key;
+mod key_list;
 mod key_ref;
 
 mod keypad;
 mod keypad_list;
 mod keypad_ref;
 
+mod library_cache;
+mod library_change;
+mod library_reload;
+mod source_stamp;
+
 mod panel;
 mod panel_list;
 mod panel_ref;
 
+mod spacer_ref;
+
+mod validation_report;
+
+pub use assembled_library::AssembledLibrary;
+pub use cache_entry::CacheEntry;
 pub use data_dir::DataDir;
 pub use data_error::DataError;
+pub use data_error_kind::DataErrorKind;
+pub use data_origin::DataOrigin;
+pub use data_root::DATA_ROOTS;
+pub use data_root::DataRoot;
+pub use data_source::DataSource;
+pub use embedded_data::EmbeddedData;
 
 pub use key::Key;
+pub use key::KeyBase;
+pub use key::KeyType;
+pub use key_list::KeyList;
 pub use key_ref::KeyRef;
 
 pub use keypad::Keypad;
 pub use keypad_list::KeypadList;
 pub use keypad_ref::KeypadRef;
 
+pub use library_cache::Cached;
+pub use library_cache::LIBRARY_CACHE;
+pub use library_cache::LibraryCache;
+pub use library_change::LibraryChange;
+pub use library_reload::LibraryReload;
+pub use source_stamp::SourceStamp;
+
 pub use panel::Panel;
 pub use panel_list::PanelList;
 pub use panel_ref::PanelRef;
+
+pub use spacer_ref::SpacerRef;
+
+pub use validation_report::ValidationReport;
@@ -0,0 +1,246 @@
+// the assembled library - the Panels, Keypads, Keys and KeyGrids a clean
+// load ended up with, and the stamp of every file that went into them,
+// keyed by the path relative to the data roots
+//
+// at the next start the library is only used if every one of those paths
+// still resolves to the same source with the same stamp - a changed file,
+// or a new one shadowing it in an earlier data root, means loading again
+// (where only the changed files get parsed)
+
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+use crate::data::CacheEntry;
+use crate::data::DATA_ROOTS;
+use crate::data::DataRoot;
+use crate::data::DataSource;
+use crate::data::Key;
+use crate::data::SourceStamp;
+use crate::data::helper::find_source_in;
+use crate::ui::KeyGrids;
+use crate::ui::KeyId;
+use crate::ui::Keypads;
+use crate::ui::Keys;
+use crate::ui::Panels;
+
+#[derive(Debug, Default, Deserialize, Serialize, Clone)]
+pub struct AssembledLibrary {
+    pub panels: Panels,
+    pub keypads: Keypads,
+    pub keys: Keys,
+    pub keygrids: KeyGrids,
+    pub sources: IndexMap<PathBuf, CacheEntry<DataSource>>,
+}
+
+impl AssembledLibrary {
+    pub fn is_current(&self) -> bool {
+        self.is_current_in(&DATA_ROOTS)
+    }
+
+    // the same check over any list of roots
+    pub fn is_current_in(&self, roots: &[DataRoot]) -> bool {
+        !self.sources.is_empty()
+            && self.sources.iter().all(|(relative_path, entry)| {
+                find_source_in(roots, relative_path).is_ok_and(|source| source == entry.value)
+                    && SourceStamp::of(&entry.value) == Some(entry.stamp)
+            })
+    }
+
+    pub fn uses(&self, source: &DataSource) -> bool {
+        self.sources.values().any(|entry| &entry.value == source)
+    }
+
+    // a key's source isn't serialized with it, so once the library has
+    // been read back in every key gets its source back from the stamps
+    pub fn restore_key_sources(&mut self) {
+        let source = |key: &Key, key_id: &KeyId| {
+            self.sources
+                .get(&Key::ron_path(key_id))
+                .map(|entry| entry.value.clone())
+                .unwrap_or_else(|| key.source().clone())
+        };
+
+        let keys: Vec<_> = (&self.keys)
+            .into_iter()
+            .map(|(key_id, key)| (key_id.clone(), key.clone().with_source(source(key, key_id))))
+            .collect();
+
+        // a keygrid key is whichever key the keypad has in that cell
+        let keygrid_keys: Vec<_> = (&self.keygrids)
+            .into_iter()
+            .map(|(keygrid_id, key)| {
+                let key_id = (&self.keypads)
+                    .into_iter()
+                    .find(|(_, keypad_view)| keypad_view.id == keygrid_id.keypad_id)
+                    .and_then(|(_, keypad_view)| {
+                        keypad_view
+                            .keys
+                            .iter()
+                            .find(|key_ref| key_ref.grid_position == keygrid_id.grid_position)
+                    })
+                    .map(|key_ref| &key_ref.key_id);
+                let key = match key_id {
+                    Some(key_id) => key.clone().with_source(source(key, key_id)),
+                    None => key.clone(),
+                };
+                (keygrid_id.clone(), key)
+            })
+            .collect();
+
+        for (key_id, key) in keys {
+            self.keys.insert(key_id, key);
+        }
+        for (keygrid_id, key) in keygrid_keys {
+            self.keygrids.insert(&keygrid_id, &key);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::DataDir;
+    use crate::data::DataOrigin;
+    use crate::data::KeyBase;
+    use crate::data::KeyRef;
+    use crate::data::KeyType;
+    use crate::data::KeypadRef;
+    use crate::ui::GridPosition;
+    use crate::ui::GridSpan;
+    use crate::ui::KeyGridId;
+    use crate::ui::KeypadView;
+    use rust_decimal::Decimal;
+    use std::fs;
+
+    // a user root in a scratch folder ahead of the built-in data
+    struct Roots {
+        scratch: PathBuf,
+        roots: Vec<DataRoot>,
+    }
+
+    impl Roots {
+        fn new(name: &str) -> Self {
+            let scratch = std::env::temp_dir().join(format!(
+                "extendcalc-assembled-{}-{}",
+                std::process::id(),
+                name
+            ));
+            let roots = vec![
+                DataRoot {
+                    origin: DataOrigin::User,
+                    path: scratch.clone(),
+                },
+                DataRoot {
+                    origin: DataOrigin::BuiltIn,
+                    path: PathBuf::from(DataDir::Data),
+                },
+            ];
+            Self { scratch, roots }
+        }
+    }
+
+    impl Drop for Roots {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.scratch);
+        }
+    }
+
+    // numbers/one on a one key keypad, stamped as the built-in file
+    fn library(roots: &Roots) -> AssembledLibrary {
+        let key_id = KeyId::new("numbers", "one");
+        let relative_path = Key::ron_path(&key_id);
+        let source = find_source_in(&roots.roots, &relative_path).unwrap();
+        let stamp = SourceStamp::of(&source).unwrap();
+
+        let base = KeyBase {
+            id: "one".to_string(),
+            label: "1".to_string(),
+            tooltip_text: String::new(),
+        };
+        let key = Key::new(
+            base,
+            KeyType::Operand {
+                value: Decimal::ONE,
+            },
+        );
+
+        let keypad_view = KeypadView {
+            id: "pad".to_string().into(),
+            rows: 1,
+            columns: 1,
+            keys: vec![KeyRef {
+                key_id: key_id.clone(),
+                grid_position: GridPosition::new(1, 1),
+                grid_span: GridSpan::new(1, 1),
+            }],
+            ..Default::default()
+        };
+        let keygrid_id = KeyGridId {
+            keypad_id: keypad_view.id.clone(),
+            grid_position: GridPosition::new(1, 1),
+        };
+
+        let mut library = AssembledLibrary::default();
+        library.keypads.insert(&KeypadRef::new("pad"), &keypad_view);
+        library.keys.insert(key_id, key.clone());
+        library.keygrids.insert(&keygrid_id, &key);
+        library.sources.insert(
+            relative_path,
+            CacheEntry {
+                stamp,
+                value: source,
+            },
+        );
+        library
+    }
+
+    #[test]
+    fn current_until_a_file_changes() {
+        let roots = Roots::new("current");
+        assert!(!AssembledLibrary::default().is_current_in(&roots.roots));
+
+        let mut library = library(&roots);
+        assert!(library.is_current_in(&roots.roots));
+
+        // the same file with a different stamp
+        for entry in library.sources.values_mut() {
+            entry.stamp.hash = Some([0; 32]);
+        }
+        assert!(!library.is_current_in(&roots.roots));
+    }
+
+    #[test]
+    fn shadowed_by_a_new_file() {
+        let roots = Roots::new("shadowed");
+        let library = library(&roots);
+
+        let path = roots
+            .scratch
+            .join(Key::ron_path(&KeyId::new("numbers", "one")));
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, "").unwrap();
+
+        assert!(!library.is_current_in(&roots.roots));
+    }
+
+    #[test]
+    fn key_sources_come_back() {
+        let roots = Roots::new("sources");
+        let library = library(&roots);
+        let source = library.sources[0].value.clone();
+        assert!(library.uses(&source));
+
+        // read back from the cache the keys have lost their sources
+        let content = ron::to_string(&library).unwrap();
+        let mut library: AssembledLibrary = ron::from_str(&content).unwrap();
+        library.restore_key_sources();
+
+        for (_, key) in &library.keys {
+            assert_eq!(key.source(), &source);
+        }
+        for (_, key) in &library.keygrids {
+            assert_eq!(key.source(), &source);
+        }
+    }
+}
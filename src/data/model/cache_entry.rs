@@ -0,0 +1,11 @@
+// one cached structure and the stamp of the file it was parsed from
+
+use serde::{Deserialize, Serialize};
+
+use crate::data::SourceStamp;
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct CacheEntry<T> {
+    pub stamp: SourceStamp,
+    pub value: T,
+}
@@ -1,14 +1,91 @@
-// this is the data error for all the RON modules
+// this is the data error for all the RON modules - the kind of failure
+// plus everything we know about where it happened, so the error view
+// can point at the file, line and column and the id that was being loaded
 
+use ron::error::Span;
+use std::path::Path;
+use std::path::PathBuf;
 use thiserror::Error;
 
-#[derive(Debug, Error)]
-pub enum DataError {
-    // Automatic conversion from std::io::Error
-    #[error("File system error: {0}")]
-    Io(#[from] std::io::Error),
+use crate::calc::CalcError;
+use crate::data::DataErrorKind;
 
-    // Automatic conversion from ron::error::SpannedError
-    #[error("RON syntax error: {0}")]
-    Ron(#[from] ron::error::SpannedError),
+#[derive(Debug, Clone, Error)]
+pub struct DataError {
+    pub kind: DataErrorKind,
+    pub path: Option<PathBuf>,
+    pub span: Option<Span>,
+    pub id: Option<String>,
+}
+
+impl DataError {
+    pub fn new(kind: DataErrorKind) -> Self {
+        Self {
+            kind,
+            path: None,
+            span: None,
+            id: None,
+        }
+    }
+
+    pub fn with_path(mut self, path: impl AsRef<Path>) -> Self {
+        self.path = Some(path.as_ref().to_path_buf());
+        self
+    }
+
+    pub fn with_span(mut self, span: Span) -> Self {
+        self.span = Some(span);
+        self
+    }
+
+    // only the first (innermost) id sticks - that's the one that failed
+    pub fn with_id(mut self, id: impl ToString) -> Self {
+        if self.id.is_none() {
+            self.id = Some(id.to_string());
+        }
+        self
+    }
+
+    // file:line:column - as much of it as we know
+    pub fn location(&self) -> Option<String> {
+        let path = self.path.as_ref()?.display();
+        match &self.span {
+            Some(span) => Some(format!("{}:{}:{}", path, span.start.line, span.start.col)),
+            None => Some(path.to_string()),
+        }
+    }
+}
+
+// Automatic conversion from std::io::Error
+impl From<std::io::Error> for DataError {
+    fn from(error: std::io::Error) -> Self {
+        Self::new(DataErrorKind::Io(error.kind(), error.to_string()))
+    }
+}
+
+// Automatic conversion from ron::error::SpannedError - keeping the span
+impl From<ron::error::SpannedError> for DataError {
+    fn from(error: ron::error::SpannedError) -> Self {
+        Self::new(DataErrorKind::Ron(error.code)).with_span(error.span)
+    }
+}
+
+// an operator term that doesn't parse or doesn't fit its arity
+impl From<CalcError> for DataError {
+    fn from(error: CalcError) -> Self {
+        Self::new(DataErrorKind::Term(error))
+    }
+}
+
+impl std::fmt::Display for DataError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(location) = self.location() {
+            write!(f, "{}: ", location)?;
+        }
+        write!(f, "{}", self.kind)?;
+        if let Some(id) = &self.id {
+            write!(f, " (loading '{}')", id)?;
+        }
+        Ok(())
+    }
 }
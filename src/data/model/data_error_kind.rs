@@ -0,0 +1,92 @@
+// what actually went wrong loading a RON definition
+
+use thiserror::Error;
+
+use crate::calc::CalcError;
+use crate::ui::GridPosition;
+use crate::ui::GridSpan;
+use crate::ui::KeyId;
+
+#[derive(Debug, Clone, Error)]
+pub enum DataErrorKind {
+    #[error("File system error: {1}")]
+    Io(std::io::ErrorKind, String),
+
+    #[error("RON syntax error: {0}")]
+    Ron(ron::Error),
+
+    #[error("Operator term error: {0}")]
+    Term(CalcError),
+
+    #[error("The panel list has no panels in it")]
+    NoPanels,
+
+    #[error("Unknown panel '{0}'")]
+    UnknownPanel(String),
+
+    #[error("Unknown keypad '{0}'")]
+    UnknownKeypad(String),
+
+    #[error("Unknown key '{0}'")]
+    UnknownKey(KeyId),
+
+    #[error("Key '{key_id}' at {position} is outside the {rows}x{columns} grid")]
+    OutOfBounds {
+        key_id: KeyId,
+        position: GridPosition,
+        span: GridSpan,
+        rows: usize,
+        columns: usize,
+    },
+
+    #[error("Key '{0}' at {1} has an empty span")]
+    EmptySpan(KeyId, GridPosition),
+
+    #[error("Id '{found}' doesn't match the file name '{expected}'")]
+    IdMismatch { expected: String, found: String },
+
+    #[error("Key '{0}' isn't used by any keypad")]
+    UnusedKey(KeyId),
+
+    #[error("Not in a library folder, no key id can reach this file")]
+    StrayFile,
+
+    #[error("Keypad '{keypad_id}' at {position} is outside the {rows}x{columns} panel")]
+    KeypadOutOfBounds {
+        keypad_id: String,
+        position: GridPosition,
+        rows: usize,
+        columns: usize,
+    },
+
+    #[error("A {rows}x{columns} grid is bigger than the {max}x{max} the editor lays out")]
+    GridTooLarge {
+        rows: usize,
+        columns: usize,
+        max: usize,
+    },
+
+    #[error("More than one keypad at {0}")]
+    DuplicateKeypadPosition(GridPosition),
+
+    #[error("More than one key at {0}")]
+    DuplicatePosition(GridPosition),
+
+    #[error("Spacer at {position} is outside the {rows}x{columns} grid")]
+    SpacerOutOfBounds {
+        position: GridPosition,
+        span: GridSpan,
+        rows: usize,
+        columns: usize,
+    },
+
+    #[error("Spacer at {0} overlaps a key or another spacer")]
+    OverlappingSpacer(GridPosition),
+
+    #[error("Key '{key_id}' overlaps key '{other_id}' at {position}")]
+    OverlappingSpan {
+        key_id: KeyId,
+        other_id: KeyId,
+        position: GridPosition,
+    },
+}
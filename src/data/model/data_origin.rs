@@ -0,0 +1,29 @@
+// which layer of the data roots a definition was found in - the roots are
+// searched in this order, so a user file shadows a system file of the
+// same id, and a system file shadows the built-in one
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Default, Serialize, Deserialize, Hash, Eq, PartialEq, Clone, Copy)]
+pub enum DataOrigin {
+    User,
+    System,
+    #[default]
+    BuiltIn,
+}
+
+impl DataOrigin {
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::User => "user",
+            Self::System => "system",
+            Self::BuiltIn => "built-in",
+        }
+    }
+}
+
+impl std::fmt::Display for DataOrigin {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
@@ -0,0 +1,65 @@
+// the ordered list of directories we look for definitions in
+//
+//   $XDG_DATA_HOME/extendcalc       (user)
+//   $XDG_DATA_DIRS/extendcalc       (system, each in turn)
+//   data/                           (built-in, embedded in the binary)
+//
+// every root has the same layout underneath - key_definitions,
+// keypad_definitions and panel_definitions
+
+use std::env;
+use std::path::PathBuf;
+use std::sync::LazyLock;
+
+use crate::data::DataDir;
+use crate::data::DataOrigin;
+
+const APP_DIR: &str = "extendcalc";
+const DEFAULT_DATA_DIRS: &str = "/usr/local/share:/usr/share";
+
+pub static DATA_ROOTS: LazyLock<Vec<DataRoot>> = LazyLock::new(DataRoot::discover);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DataRoot {
+    pub origin: DataOrigin,
+    pub path: PathBuf,
+}
+
+impl DataRoot {
+    pub fn discover() -> Vec<Self> {
+        let mut roots = Vec::new();
+
+        if let Some(data_home) = dirs::data_dir() {
+            roots.push(Self {
+                origin: DataOrigin::User,
+                path: data_home.join(APP_DIR),
+            });
+        }
+
+        // relative entries in XDG_DATA_DIRS are invalid per the spec
+        let data_dirs = env::var("XDG_DATA_DIRS")
+            .ok()
+            .filter(|dirs| !dirs.is_empty())
+            .unwrap_or_else(|| DEFAULT_DATA_DIRS.to_string());
+        for data_dir in env::split_paths(&data_dirs).filter(|dir| dir.is_absolute()) {
+            roots.push(Self {
+                origin: DataOrigin::System,
+                path: data_dir.join(APP_DIR),
+            });
+        }
+
+        roots.push(Self {
+            origin: DataOrigin::BuiltIn,
+            path: PathBuf::from(DataDir::Data),
+        });
+
+        roots
+    }
+
+    // the user root is the one we write to
+    pub fn user() -> Option<&'static Self> {
+        DATA_ROOTS
+            .iter()
+            .find(|root| root.origin == DataOrigin::User)
+    }
+}
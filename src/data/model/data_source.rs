@@ -0,0 +1,65 @@
+// where a definition was actually loaded from - the root it came out of
+// and the full path, so the UI can show it and errors can point at it
+//
+// built-in definitions live in the binary, their path is the data/ path
+// they were embedded from
+
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+
+use crate::data::DataDir;
+use crate::data::DataOrigin;
+use crate::data::EmbeddedData;
+
+#[derive(Debug, Default, Serialize, Deserialize, Hash, Eq, PartialEq, Clone)]
+pub struct DataSource {
+    pub origin: DataOrigin,
+    pub path: PathBuf,
+}
+
+impl DataSource {
+    pub fn exists(&self) -> bool {
+        match self.origin {
+            DataOrigin::BuiltIn => self
+                .embedded_path()
+                .is_some_and(|path| EmbeddedData::get(&path).is_some()),
+            _ => self.path.is_file(),
+        }
+    }
+
+    pub fn read_to_string(&self) -> io::Result<String> {
+        match self.origin {
+            DataOrigin::BuiltIn => {
+                let file = self
+                    .embedded_path()
+                    .and_then(|path| EmbeddedData::get(&path))
+                    .ok_or(io::ErrorKind::NotFound)?;
+                String::from_utf8(file.data.into_owned())
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+            }
+            _ => fs::read_to_string(&self.path),
+        }
+    }
+
+    // the embedded file name is the path inside data/
+    fn embedded_path(&self) -> Option<String> {
+        self.path
+            .strip_prefix(Path::new(DataDir::Data.as_str()))
+            .ok()
+            .map(|path| path.to_string_lossy().into_owned())
+    }
+
+    pub fn embedded_hash(&self) -> Option<[u8; 32]> {
+        let path = self.embedded_path()?;
+        EmbeddedData::get(&path).map(|file| file.metadata.sha256_hash())
+    }
+}
+
+impl std::fmt::Display for DataSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({})", self.path.display(), self.origin)
+    }
+}
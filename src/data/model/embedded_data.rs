@@ -0,0 +1,9 @@
+// the shipped data/ tree, built into the binary the same way i18n/ is -
+// this is the built-in root, so a fresh install has the default library
+// even though nothing gets copied to /usr/share
+
+use rust_embed::RustEmbed;
+
+#[derive(RustEmbed)]
+#[folder = "data/"]
+pub struct EmbeddedData;
@@ -1,13 +1,22 @@
 // build a key from the RON file
 
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use std::ops::Deref;
+use std::path::PathBuf;
 
+use crate::calc::Arity;
+use crate::calc::CalcError;
+use crate::calc::OPERATIONS;
+use crate::calc::TermTemplate;
+use crate::calc::helper::check_term;
 use crate::data::DataDir;
 use crate::data::DataError;
-use crate::data::KeyRef;
-use crate::data::helper::load_and_parse;
+use crate::data::DataSource;
+use crate::data::helper::find_source;
+use crate::data::helper::load_cached;
 use crate::data::helper::path_builder;
+use crate::ui::KeyId;
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct KeyBase {
@@ -19,9 +28,34 @@ pub struct KeyBase {
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub enum KeyType {
-    Operand { value: f64 },
-    Operator { qalc_term: String },
-    Internal { operation: String },
+    Operand {
+        value: Decimal,
+    },
+    Operator {
+        qalc_term: TermTemplate,
+        arity: Arity,
+    },
+    Internal {
+        operation: String,
+        // the memory register a MEMORY_ operation works on, M if it's empty
+        #[serde(default, skip_serializing_if = "String::is_empty")]
+        register: String,
+    },
+}
+
+impl KeyType {
+    // operator terms are checked against their arity and internal keys
+    // against the operations there are, so a typo is caught when the key
+    // loads rather than when it's pressed
+    pub fn check(&self) -> Result<(), CalcError> {
+        match self {
+            KeyType::Operator { qalc_term, arity } => check_term(qalc_term, *arity),
+            KeyType::Internal { operation, .. } if !OPERATIONS.contains(&operation.as_str()) => {
+                Err(CalcError::UnknownOperation(operation.clone()))
+            }
+            _ => Ok(()),
+        }
+    }
 }
 
 //  bow read our Keypads structure from the RON file
@@ -30,17 +64,52 @@ pub enum KeyType {
 pub struct Key {
     base: KeyBase,
     key_type: KeyType,
+    #[serde(skip)]
+    source: DataSource,
 }
 
 impl Key {
-    pub fn from_ron(key_ref: &KeyRef) -> Result<Self, DataError> {
-        let key_path = path_builder(
-            DataDir::Data,
-            DataDir::KeyDefinitions.as_str(),
-            &key_ref.key_id.library,
-            &key_ref.key_id.id,
-        );
-        load_and_parse::<Self>(&key_path)
+    pub fn new(base: KeyBase, key_type: KeyType) -> Self {
+        Self {
+            base,
+            key_type,
+            source: DataSource::default(),
+        }
+    }
+
+    // relative to whichever data root it turns up in
+    pub fn ron_path(key_id: &KeyId) -> PathBuf {
+        path_builder(DataDir::KeyDefinitions, &key_id.library, "", &key_id.id)
+    }
+
+    pub fn from_ron(key_id: &KeyId) -> Result<Self, DataError> {
+        let source = find_source(&Self::ron_path(key_id)).map_err(|e| e.with_id(key_id))?;
+        Self::from_source(&source, key_id)
+    }
+
+    // load from a known file rather than searching the data roots
+    pub fn from_source(source: &DataSource, key_id: &KeyId) -> Result<Self, DataError> {
+        let mut key = load_cached::<Self>(source).map_err(|e| e.with_id(key_id))?;
+
+        key.key_type
+            .check()
+            .map_err(|e| DataError::from(e).with_path(&source.path).with_id(key_id))?;
+
+        key.source = source.clone();
+        Ok(key)
+    }
+
+    pub fn key_type(&self) -> &KeyType {
+        &self.key_type
+    }
+
+    pub fn source(&self) -> &DataSource {
+        &self.source
+    }
+
+    pub fn with_source(mut self, source: DataSource) -> Self {
+        self.source = source;
+        self
     }
 }
 
@@ -51,3 +120,42 @@ impl Deref for Key {
         &self.base
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn internal(operation: &str) -> KeyType {
+        KeyType::Internal {
+            operation: operation.to_string(),
+            register: String::new(),
+        }
+    }
+
+    #[test]
+    fn check() {
+        for operation in OPERATIONS {
+            assert_eq!(internal(operation).check(), Ok(()), "{}", operation);
+        }
+        for operation in ["", "EQUAL", "equals", "MEMORY_STORE"] {
+            assert_eq!(
+                internal(operation).check(),
+                Err(CalcError::UnknownOperation(operation.to_string()))
+            );
+        }
+
+        let operator = |qalc_term: &str, arity| KeyType::Operator {
+            qalc_term: TermTemplate::try_from(qalc_term.to_string()).unwrap(),
+            arity,
+        };
+        assert_eq!(operator(" * 1.15", Arity::PostfixUnary).check(), Ok(()));
+        assert!(operator(" * ", Arity::PostfixUnary).check().is_err());
+        assert_eq!(
+            KeyType::Operand {
+                value: Decimal::ONE
+            }
+            .check(),
+            Ok(())
+        );
+    }
+}
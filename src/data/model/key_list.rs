@@ -0,0 +1,44 @@
+// a list of unique keys referenced by the loaded keypads
+
+use crate::ui::KeyId;
+
+#[derive(Debug, Default, Clone)]
+pub struct KeyList {
+    pub key_ids: Vec<KeyId>,
+}
+
+impl KeyList {
+    pub fn push(&mut self, key_id: KeyId) {
+        self.key_ids.push(key_id);
+    }
+
+    pub fn len(&self) -> usize {
+        self.key_ids.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.key_ids.is_empty()
+    }
+
+    pub fn contains(&self, key_id: &KeyId) -> bool {
+        self.key_ids.contains(key_id)
+    }
+}
+
+impl<'a> IntoIterator for &'a KeyList {
+    type Item = &'a KeyId;
+    type IntoIter = std::slice::Iter<'a, KeyId>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.key_ids.iter()
+    }
+}
+
+impl IntoIterator for KeyList {
+    type Item = KeyId;
+    type IntoIter = std::vec::IntoIter<KeyId>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.key_ids.into_iter()
+    }
+}
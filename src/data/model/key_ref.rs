@@ -1,8 +1,8 @@
 // KeyRef - lets link the 3NF stuff in the RON dirs with the View stuff
 //
-use crate::data::KeyId;
 use crate::ui::GridPosition;
 use crate::ui::GridSpan;
+use crate::ui::KeyId;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Default, Serialize, Deserialize, Hash, Eq, PartialEq, Clone)]
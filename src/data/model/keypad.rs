@@ -1,12 +1,16 @@
 // build a keypad from the RON file
 
 use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
 
 use crate::data::DataDir;
 use crate::data::DataError;
+use crate::data::DataSource;
 use crate::data::KeyRef;
 use crate::data::KeypadRef;
-use crate::data::helper::load_and_parse;
+use crate::data::SpacerRef;
+use crate::data::helper::find_source;
+use crate::data::helper::load_cached;
 use crate::data::helper::path_builder;
 
 //  bow read our Keypads structure from the RON file
@@ -19,16 +23,29 @@ pub struct Keypad {
     pub rows: usize,
     pub columns: usize,
     pub keys: Vec<KeyRef>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub spacers: Vec<SpacerRef>,
+    #[serde(skip)]
+    pub source: DataSource,
 }
 
 impl Keypad {
+    // relative to whichever data root it turns up in
+    pub fn ron_path(keypad_ref: &KeypadRef) -> PathBuf {
+        path_builder(DataDir::KeypadDefinitions, "", "", &keypad_ref.id)
+    }
+
     pub fn from_ron(keypad_ref: &KeypadRef) -> Result<Self, DataError> {
-        let keypad_path = path_builder(
-            DataDir::Data,
-            DataDir::KeypadDefinitions,
-            "",
-            &keypad_ref.id,
-        );
-        load_and_parse::<Self>(&keypad_path)
+        let source =
+            find_source(&Self::ron_path(keypad_ref)).map_err(|e| e.with_id(&keypad_ref.id))?;
+        Self::from_source(&source, keypad_ref)
+    }
+
+    // load from a known file rather than searching the data roots
+    pub fn from_source(source: &DataSource, keypad_ref: &KeypadRef) -> Result<Self, DataError> {
+        let mut keypad = load_cached::<Self>(source).map_err(|e| e.with_id(&keypad_ref.id))?;
+
+        keypad.source = source.clone();
+        Ok(keypad)
     }
 }
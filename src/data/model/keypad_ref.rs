@@ -1,8 +1,46 @@
 // KeypadRef - a key into the Keypad collection
+//
+// on a panel it also says where the keypad goes - row and column are 1 based
+// like a key's grid position. where it sits isn't part of which keypad it is,
+// so the lookups only compare and hash the id
 use serde::{Deserialize, Serialize};
+use std::hash::{Hash, Hasher};
+
+use crate::ui::GridPosition;
 
 //  deserialize the keypad reference structure
-#[derive(Debug, Default, Serialize, Deserialize, Hash, Eq, PartialEq, Clone)]
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
 pub struct KeypadRef {
     pub id: String,
+    #[serde(default)]
+    pub row: usize,
+    #[serde(default)]
+    pub column: usize,
+}
+
+impl KeypadRef {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn grid_position(&self) -> GridPosition {
+        GridPosition::new(self.row, self.column)
+    }
+}
+
+impl PartialEq for KeypadRef {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for KeypadRef {}
+
+impl Hash for KeypadRef {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
 }
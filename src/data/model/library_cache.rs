@@ -0,0 +1,212 @@
+// the compiled startup cache - every PanelList, Panel, Keypad and Key we've
+// parsed, keyed by the source it came from and stamped with a fingerprint
+// of that source. a changed file only invalidates its own entry
+//
+// on top of those sits the library they were last assembled into, stamped
+// with the same fingerprints - while none of its files have changed a start
+// takes it as it is and skips loading altogether
+//
+// the cache lives in $XDG_CACHE_HOME/extendcalc and is shared by all the
+// async load tasks, so it sits behind a mutex
+
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+use std::sync::LazyLock;
+use std::sync::Mutex;
+
+use crate::data::AssembledLibrary;
+use crate::data::CacheEntry;
+use crate::data::DataError;
+use crate::data::DataErrorKind;
+use crate::data::DataSource;
+use crate::data::Key;
+use crate::data::Keypad;
+use crate::data::Panel;
+use crate::data::PanelList;
+use crate::data::PanelRef;
+use crate::data::helper::find_source;
+use crate::ui::KeyGrids;
+use crate::ui::Keypads;
+use crate::ui::Keys;
+use crate::ui::Panels;
+
+// bump this whenever the shape of the cached structures changes
+const CACHE_VERSION: u32 = 5;
+
+pub static LIBRARY_CACHE: LazyLock<Mutex<LibraryCache>> =
+    LazyLock::new(|| Mutex::new(LibraryCache::load()));
+
+#[derive(Debug, Default, Deserialize, Serialize, Clone)]
+pub struct LibraryCache {
+    version: u32,
+    panel_lists: IndexMap<DataSource, CacheEntry<PanelList>>,
+    panels: IndexMap<DataSource, CacheEntry<Panel>>,
+    keypads: IndexMap<DataSource, CacheEntry<Keypad>>,
+    keys: IndexMap<DataSource, CacheEntry<Key>>,
+    library: Option<AssembledLibrary>,
+}
+
+// the structures that can be cached - each one knows its own map
+pub trait Cached: Clone + Sized {
+    fn entries(cache: &mut LibraryCache) -> &mut IndexMap<DataSource, CacheEntry<Self>>;
+}
+
+impl LibraryCache {
+    pub fn path() -> Option<PathBuf> {
+        dirs::cache_dir().map(|dir| dir.join("extendcalc").join("library_cache.ron"))
+    }
+
+    // a missing, unreadable or out of date cache is just an empty one
+    pub fn load() -> Self {
+        Self::path()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .and_then(|content| ron::from_str::<Self>(&content).ok())
+            .filter(|cache| cache.version == CACHE_VERSION)
+            .unwrap_or_default()
+    }
+
+    pub fn save(&mut self) -> Result<(), DataError> {
+        let Some(path) = Self::path() else {
+            return Ok(());
+        };
+
+        // drop anything whose source file has gone away
+        self.panel_lists.retain(|source, _| source.exists());
+        self.panels.retain(|source, _| source.exists());
+        self.keypads.retain(|source, _| source.exists());
+        self.keys.retain(|source, _| source.exists());
+        self.version = CACHE_VERSION;
+
+        let content = ron::to_string(self)
+            .map_err(|e| DataError::new(DataErrorKind::Ron(e)).with_path(&path))?;
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|e| DataError::from(e).with_path(dir))?;
+        }
+        fs::write(&path, content).map_err(|e| DataError::from(e).with_path(&path))
+    }
+
+    pub fn get<T: Cached>(&mut self, source: &DataSource) -> Option<&CacheEntry<T>> {
+        T::entries(self).get(source)
+    }
+
+    pub fn insert<T: Cached>(&mut self, source: &DataSource, entry: CacheEntry<T>) {
+        T::entries(self).insert(source.clone(), entry);
+    }
+
+    pub fn invalidate(&mut self, source: &DataSource) {
+        self.panel_lists.shift_remove(source);
+        self.panels.shift_remove(source);
+        self.keypads.shift_remove(source);
+        self.keys.shift_remove(source);
+
+        if self
+            .library
+            .as_ref()
+            .is_some_and(|library| library.uses(source))
+        {
+            self.library = None;
+        }
+    }
+
+    // the assembled library, as long as none of its files have changed
+    pub fn library(&self) -> Option<AssembledLibrary> {
+        let mut library = self
+            .library
+            .as_ref()
+            .filter(|library| library.is_current())?
+            .clone();
+        library.restore_key_sources();
+        Some(library)
+    }
+
+    // keep the assembled library, stamped with the stamps its files were
+    // parsed with - if one of them has no entry it can't be checked next
+    // time, so no library is kept at all
+    pub fn store_library(
+        &mut self,
+        panels: Panels,
+        keypads: Keypads,
+        keys: Keys,
+        keygrids: KeyGrids,
+    ) {
+        let sources = self.stamp_sources(&panels, &keypads, &keys);
+
+        self.library = sources.map(|sources| AssembledLibrary {
+            panels,
+            keypads,
+            keys,
+            keygrids,
+            sources,
+        });
+    }
+
+    fn stamp_sources(
+        &self,
+        panels: &Panels,
+        keypads: &Keypads,
+        keys: &Keys,
+    ) -> Option<IndexMap<PathBuf, CacheEntry<DataSource>>> {
+        let mut sources = IndexMap::new();
+        let entry = |stamp, source: &DataSource| CacheEntry {
+            stamp,
+            value: source.clone(),
+        };
+
+        let panel_list = find_source(&PanelList::ron_path()).ok()?;
+        let stamp = self.panel_lists.get(&panel_list)?.stamp;
+        sources.insert(PanelList::ron_path(), entry(stamp, &panel_list));
+
+        for panel_view in panels {
+            let stamp = self.panels.get(&panel_view.source)?.stamp;
+            let panel_ref = PanelRef {
+                id: panel_view.id.clone(),
+            };
+            sources.insert(
+                Panel::ron_path(&panel_ref),
+                entry(stamp, &panel_view.source),
+            );
+        }
+
+        for (keypad_ref, keypad_view) in keypads {
+            let stamp = self.keypads.get(&keypad_view.source)?.stamp;
+            sources.insert(
+                Keypad::ron_path(keypad_ref),
+                entry(stamp, &keypad_view.source),
+            );
+        }
+
+        for (key_id, key) in keys {
+            let stamp = self.keys.get(key.source())?.stamp;
+            sources.insert(Key::ron_path(key_id), entry(stamp, key.source()));
+        }
+
+        Some(sources)
+    }
+}
+
+impl Cached for PanelList {
+    fn entries(cache: &mut LibraryCache) -> &mut IndexMap<DataSource, CacheEntry<Self>> {
+        &mut cache.panel_lists
+    }
+}
+
+impl Cached for Panel {
+    fn entries(cache: &mut LibraryCache) -> &mut IndexMap<DataSource, CacheEntry<Self>> {
+        &mut cache.panels
+    }
+}
+
+impl Cached for Keypad {
+    fn entries(cache: &mut LibraryCache) -> &mut IndexMap<DataSource, CacheEntry<Self>> {
+        &mut cache.keypads
+    }
+}
+
+impl Cached for Key {
+    fn entries(cache: &mut LibraryCache) -> &mut IndexMap<DataSource, CacheEntry<Self>> {
+        &mut cache.keys
+    }
+}
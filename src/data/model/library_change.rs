@@ -0,0 +1,114 @@
+// a changed file in one of the data roots, worked out from its path -
+// anything that isn't a definition file is ignored
+//
+//   panel_definitions/panel_list.ron
+//   panel_definitions/<panel>.ron
+//   keypad_definitions/<keypad>.ron
+//   key_definitions/<library>/<key>.ron
+
+use std::path::Path;
+
+use crate::data::DATA_ROOTS;
+use crate::data::DataDir;
+use crate::data::KeypadRef;
+use crate::data::PanelRef;
+use crate::ui::KeyId;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LibraryChange {
+    PanelList,
+    Panel(PanelRef),
+    Keypad(KeypadRef),
+    Key(KeyId),
+}
+
+impl LibraryChange {
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let relative = DATA_ROOTS
+            .iter()
+            .find_map(|root| path.strip_prefix(&root.path).ok())?;
+        Self::from_relative(relative)
+    }
+
+    // the path inside a data root
+    pub fn from_relative(relative: &Path) -> Option<Self> {
+        if relative.extension()? != "ron" {
+            return None;
+        }
+
+        let id = relative.file_stem()?.to_str()?.to_string();
+        let folders: Vec<&str> = relative
+            .parent()?
+            .iter()
+            .map(|folder| folder.to_str())
+            .collect::<Option<_>>()?;
+
+        match folders.as_slice() {
+            [folder] if *folder == DataDir::PanelDefinitions.as_str() => match id.as_str() {
+                "panel_list" => Some(Self::PanelList),
+                _ => Some(Self::Panel(PanelRef { id })),
+            },
+            [folder] if *folder == DataDir::KeypadDefinitions.as_str() => {
+                Some(Self::Keypad(KeypadRef::new(id)))
+            }
+            [folder, library] if *folder == DataDir::KeyDefinitions.as_str() => {
+                Some(Self::Key(KeyId::new(*library, id)))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_relative() {
+        let cases = [
+            (
+                "panel_definitions/panel_list.ron",
+                Some(LibraryChange::PanelList),
+            ),
+            (
+                "panel_definitions/business.ron",
+                Some(LibraryChange::Panel(PanelRef {
+                    id: "business".to_string(),
+                })),
+            ),
+            (
+                "keypad_definitions/numbers.ron",
+                Some(LibraryChange::Keypad(KeypadRef::new("numbers"))),
+            ),
+            (
+                "key_definitions/scientific/square.ron",
+                Some(LibraryChange::Key(KeyId::new("scientific", "square"))),
+            ),
+            ("panel_definitions/business.ron~", None),
+            ("panel_definitions/.business.ron.swp", None),
+            ("panel_definitions/nested/business.ron", None),
+            ("keypad_definitions/numbers/extra.ron", None),
+            ("key_definitions/square.ron", None),
+            ("key_definitions/a/b/square.ron", None),
+            ("session_definitions/work.ron", None),
+            ("panel_list.ron", None),
+        ];
+
+        for (path, expected) in cases {
+            assert_eq!(
+                LibraryChange::from_relative(Path::new(path)),
+                expected,
+                "{}",
+                path
+            );
+        }
+    }
+
+    #[test]
+    fn outside_the_data_roots() {
+        assert_eq!(
+            LibraryChange::from_path(Path::new("/nowhere/panel_definitions/business.ron")),
+            None
+        );
+    }
+}
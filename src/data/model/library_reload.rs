@@ -0,0 +1,88 @@
+// everything a hot reload has to swap in - the changed definition plus
+// whatever it references, so a reloaded keypad comes back with its keys
+// and a reloaded panel with its keypads. anything that fails to load
+// ends up in the report and nothing gets swapped in
+
+use crate::data::Key;
+use crate::data::Keypad;
+use crate::data::KeypadRef;
+use crate::data::LibraryChange;
+use crate::data::Panel;
+use crate::data::PanelList;
+use crate::data::PanelRef;
+use crate::data::ValidationReport;
+use crate::ui::KeyId;
+
+#[derive(Debug, Default, Clone)]
+pub struct LibraryReload {
+    pub panel_list: Option<PanelList>,
+    pub panels: Vec<Panel>,
+    pub keypads: Vec<(KeypadRef, Keypad)>,
+    pub keys: Vec<(KeyId, Key)>,
+}
+
+impl LibraryReload {
+    pub fn load(change: &LibraryChange) -> Result<Self, ValidationReport> {
+        let mut reload = Self::default();
+        let mut report = ValidationReport::default();
+
+        match change {
+            LibraryChange::PanelList => match PanelList::from_ron() {
+                Ok(panel_list) => {
+                    for panel_ref in &panel_list {
+                        reload.load_panel(panel_ref, &mut report);
+                    }
+                    reload.panel_list = Some(panel_list);
+                }
+                Err(error) => report.push(error),
+            },
+            LibraryChange::Panel(panel_ref) => reload.load_panel(panel_ref, &mut report),
+            LibraryChange::Keypad(keypad_ref) => reload.load_keypad(keypad_ref, &mut report),
+            LibraryChange::Key(key_id) => reload.load_key(key_id, &mut report),
+        }
+
+        match report.is_empty() {
+            true => Ok(reload),
+            false => Err(report),
+        }
+    }
+
+    fn load_panel(&mut self, panel_ref: &PanelRef, report: &mut ValidationReport) {
+        match Panel::from_ron(panel_ref) {
+            Ok(panel) => {
+                for keypad_ref in &panel.keypads {
+                    self.load_keypad(keypad_ref, report);
+                }
+                self.panels.push(panel);
+            }
+            Err(error) => report.push(error),
+        }
+    }
+
+    fn load_keypad(&mut self, keypad_ref: &KeypadRef, report: &mut ValidationReport) {
+        if self.keypads.iter().any(|(loaded, _)| loaded == keypad_ref) {
+            return;
+        }
+
+        match Keypad::from_ron(keypad_ref) {
+            Ok(keypad) => {
+                for key_ref in &keypad.keys {
+                    self.load_key(&key_ref.key_id, report);
+                }
+                self.keypads.push((keypad_ref.clone(), keypad));
+            }
+            Err(error) => report.push(error),
+        }
+    }
+
+    fn load_key(&mut self, key_id: &KeyId, report: &mut ValidationReport) {
+        if self.keys.iter().any(|(loaded, _)| loaded == key_id) {
+            return;
+        }
+
+        match Key::from_ron(key_id) {
+            Ok(key) => self.keys.push((key_id.clone(), key)),
+            Err(error) => report.push(error),
+        }
+    }
+}
@@ -1,12 +1,15 @@
 // build a panel from the RON file
 
 use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
 
 use crate::data::DataDir;
 use crate::data::DataError;
+use crate::data::DataSource;
 use crate::data::KeypadRef;
 use crate::data::PanelRef;
-use crate::data::helper::load_and_parse;
+use crate::data::helper::find_source;
+use crate::data::helper::load_cached;
 use crate::data::helper::path_builder;
 
 //  bow read our Panel structure from the RON file
@@ -19,11 +22,27 @@ pub struct Panel {
     pub rows: usize,
     pub columns: usize,
     pub keypads: Vec<KeypadRef>,
+    #[serde(skip)]
+    pub source: DataSource,
 }
 
 impl Panel {
+    // relative to whichever data root it turns up in
+    pub fn ron_path(panel_ref: &PanelRef) -> PathBuf {
+        path_builder(DataDir::PanelDefinitions, "", "", &panel_ref.id)
+    }
+
     pub fn from_ron(panel_ref: &PanelRef) -> Result<Self, DataError> {
-        let panel_path = path_builder(DataDir::Data, DataDir::PanelDefinitions, "", &panel_ref.id);
-        load_and_parse::<Self>(&panel_path)
+        let source =
+            find_source(&Self::ron_path(panel_ref)).map_err(|e| e.with_id(&panel_ref.id))?;
+        Self::from_source(&source, panel_ref)
+    }
+
+    // load from a known file rather than searching the data roots
+    pub fn from_source(source: &DataSource, panel_ref: &PanelRef) -> Result<Self, DataError> {
+        let mut panel = load_cached::<Self>(source).map_err(|e| e.with_id(&panel_ref.id))?;
+
+        panel.source = source.clone();
+        Ok(panel)
     }
 }
@@ -1,11 +1,14 @@
 // build a list of panels from the RON file
 
 use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
 
 use crate::data::DataDir;
 use crate::data::DataError;
+use crate::data::DataSource;
 use crate::data::PanelRef;
-use crate::data::helper::load_and_parse;
+use crate::data::helper::find_source;
+use crate::data::helper::load_cached;
 use crate::data::helper::path_builder;
 
 #[derive(Debug, Default, Deserialize, Serialize, Clone)]
@@ -16,14 +19,19 @@ pub struct PanelList {
 //  bow read our Keypads structure from the RON file
 
 impl PanelList {
+    // relative to whichever data root it turns up in
+    pub fn ron_path() -> PathBuf {
+        path_builder(DataDir::PanelDefinitions, "", "", "panel_list")
+    }
+
     pub fn from_ron() -> Result<Self, DataError> {
-        let list_path = path_builder(
-            DataDir::Data,
-            DataDir::PanelDefinitions.as_str(),
-            "",
-            "panel_list",
-        );
-        load_and_parse::<Self>(&list_path)
+        let source = find_source(&Self::ron_path()).map_err(|e| e.with_id("panel_list"))?;
+        Self::from_source(&source)
+    }
+
+    // load from a known file rather than searching the data roots
+    pub fn from_source(source: &DataSource) -> Result<Self, DataError> {
+        load_cached::<Self>(source).map_err(|e| e.with_id("panel_list"))
     }
 
     pub fn push(&mut self, panel_ref: PanelRef) {
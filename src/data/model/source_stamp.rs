@@ -0,0 +1,39 @@
+// a cheap fingerprint of a source RON file so the cache can tell if the
+// file has changed without re-reading it - modified time and size for
+// files on disk, the content hash for the ones built into the binary
+
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::time::UNIX_EPOCH;
+
+use crate::data::DataOrigin;
+use crate::data::DataSource;
+
+#[derive(Debug, Default, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
+pub struct SourceStamp {
+    pub modified_secs: u64,
+    pub modified_nanos: u32,
+    pub len: u64,
+    pub hash: Option<[u8; 32]>,
+}
+
+impl SourceStamp {
+    pub fn of(source: &DataSource) -> Option<Self> {
+        if source.origin == DataOrigin::BuiltIn {
+            return source.embedded_hash().map(|hash| Self {
+                hash: Some(hash),
+                ..Self::default()
+            });
+        }
+
+        let metadata = fs::metadata(&source.path).ok()?;
+        let modified = metadata.modified().ok()?.duration_since(UNIX_EPOCH).ok()?;
+
+        Some(Self {
+            modified_secs: modified.as_secs(),
+            modified_nanos: modified.subsec_nanos(),
+            len: metadata.len(),
+            hash: None,
+        })
+    }
+}
@@ -0,0 +1,44 @@
+// SpacerRef - a keypad cell that deliberately has no key in it
+//
+//   Spacer     just keeps the cells empty
+//   Separator  draws a line through the cells to group the keys either side
+//
+// a separator wider than it is tall is drawn across, otherwise down
+use serde::{Deserialize, Serialize};
+
+use crate::ui::GridPosition;
+use crate::ui::GridSpan;
+
+#[derive(Debug, Serialize, Deserialize, Hash, Eq, PartialEq, Clone)]
+pub enum SpacerRef {
+    Spacer {
+        grid_position: GridPosition,
+        #[serde(default)]
+        grid_span: GridSpan,
+    },
+    Separator {
+        grid_position: GridPosition,
+        #[serde(default)]
+        grid_span: GridSpan,
+    },
+}
+
+impl SpacerRef {
+    pub fn grid_position(&self) -> GridPosition {
+        match self {
+            Self::Spacer { grid_position, .. } | Self::Separator { grid_position, .. } => {
+                *grid_position
+            }
+        }
+    }
+
+    pub fn grid_span(&self) -> GridSpan {
+        match self {
+            Self::Spacer { grid_span, .. } | Self::Separator { grid_span, .. } => *grid_span,
+        }
+    }
+
+    pub fn is_separator(&self) -> bool {
+        matches!(self, Self::Separator { .. })
+    }
+}
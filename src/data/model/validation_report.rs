@@ -0,0 +1,62 @@
+// every problem found loading and checking the library - we collect them
+// all rather than stopping at the first so they can be fixed in one go
+
+use crate::data::DataError;
+
+#[derive(Debug, Default, Clone)]
+pub struct ValidationReport {
+    errors: Vec<DataError>,
+}
+
+impl ValidationReport {
+    pub fn push(&mut self, error: DataError) {
+        self.errors.push(error);
+    }
+
+    pub fn extend(&mut self, other: ValidationReport) {
+        self.errors.extend(other.errors);
+    }
+
+    pub fn len(&self) -> usize {
+        self.errors.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+impl From<DataError> for ValidationReport {
+    fn from(error: DataError) -> Self {
+        Self {
+            errors: vec![error],
+        }
+    }
+}
+
+impl std::fmt::Display for ValidationReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for error in &self.errors {
+            writeln!(f, "{}", error)?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a> IntoIterator for &'a ValidationReport {
+    type Item = &'a DataError;
+    type IntoIter = std::slice::Iter<'a, DataError>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.iter()
+    }
+}
+
+impl IntoIterator for ValidationReport {
+    type Item = DataError;
+    type IntoIter = std::vec::IntoIter<DataError>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.into_iter()
+    }
+}
@@ -0,0 +1,12 @@
+pub mod helper;
+mod model;
+
+pub(crate) use model::ARITY_NAMES;
+pub(crate) use model::KeyEditor;
+pub(crate) use model::KeyEditorMessage;
+pub(crate) use model::KeyTypeKind;
+pub(crate) use model::KeypadEditor;
+pub(crate) use model::KeypadEditorMessage;
+pub(crate) use model::PanelComposer;
+pub(crate) use model::PanelComposerMessage;
+pub(crate) use model::PanelDraft;
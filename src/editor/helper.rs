@@ -0,0 +1,9 @@
+mod key_editor_update;
+mod keypad_editor_update;
+mod panel_composer_update;
+mod render_key_editor;
+mod render_keypad_editor;
+mod render_panel_composer;
+mod valid_id;
+
+pub(crate) use valid_id::valid_id;
@@ -0,0 +1,119 @@
+// handler for the key editor messages - every edit re-checks the form,
+// saving writes key_definitions/<library>/<id>.ron into the user data root
+
+use cosmic::app::Task;
+use cosmic::iced::window;
+
+use crate::app::Message;
+use crate::config::Config;
+use crate::data::Key;
+use crate::data::helper::save_ron;
+use crate::editor::KeyEditor;
+use crate::editor::KeyEditorMessage;
+use crate::editor::KeyTypeKind;
+use crate::editor::helper::valid_id;
+
+impl KeyEditor {
+    pub fn update(&mut self, message: KeyEditorMessage, config: &Config) -> Task<Message> {
+        use KeyEditorMessage::*;
+
+        match message {
+            Opened(_) => return Task::none(),
+
+            Open(index) => {
+                let key = self
+                    .key_ids
+                    .get(index)
+                    .and_then(|key_id| Some((key_id.clone(), Key::from_ron(key_id).ok()?)));
+                self.start(key, config);
+                return self.preview_task(config);
+            }
+            New => {
+                self.start(None, config);
+                return self.preview_task(config);
+            }
+
+            SetLibrary(library) => self.library = library,
+            SetId(id) => self.id = id,
+            SetLabel(label) => self.label = label,
+            SetTooltip(tooltip) => self.tooltip = tooltip,
+            SelectKind(index) => {
+                self.kind = KeyTypeKind::ALL.get(index).copied().unwrap_or_default()
+            }
+
+            SetValue(value) => self.value = value,
+            SetTerm(qalc_term) => self.qalc_term = qalc_term,
+            SelectArity(index) => self.arity = index,
+            SetOperands(operands) => self.operands = operands,
+            SelectOperation(index) => self.operation = index,
+            SetRegister(register) => self.register = register,
+
+            SetSample(sample) => self.sample = sample,
+            Previewed(generation, preview) => {
+                if generation == self.preview_generation {
+                    self.preview = Some(preview);
+                }
+                return Task::none();
+            }
+
+            Save => return self.save(),
+            Saved(Ok(path)) => {
+                self.status = Some(format!("Saved {}", path.display()));
+                return Task::none();
+            }
+            Saved(Err(error)) => {
+                self.status = Some(format!("Save failed: {}", error));
+                return Task::none();
+            }
+
+            Close => {
+                return match self.window_id.take() {
+                    Some(window_id) => window::close(window_id),
+                    None => Task::none(),
+                };
+            }
+        }
+
+        self.refresh(config);
+        self.preview_task(config)
+    }
+
+    // work out a waiting preview on the blocking pool
+    pub fn preview_task(&mut self, config: &Config) -> Task<Message> {
+        let Some((generation, work_out)) = self.take_pending_preview(config) else {
+            return Task::none();
+        };
+
+        Task::future(async move {
+            let preview = tokio::task::spawn_blocking(work_out)
+                .await
+                .unwrap_or_else(|error| format!("The preview failed: {}", error));
+            cosmic::action::app(Message::KeyEditor(KeyEditorMessage::Previewed(
+                generation, preview,
+            )))
+        })
+    }
+
+    fn save(&mut self) -> Task<Message> {
+        let key_id = self.key_id();
+        if !valid_id(&key_id.library) || !valid_id(&key_id.id) {
+            self.status =
+                Some("The library and id can only use letters, digits, '_' and '-'".to_string());
+            return Task::none();
+        }
+
+        let key = match self.key() {
+            Ok(key) => key,
+            Err(problem) => {
+                self.status = Some(problem);
+                return Task::none();
+            }
+        };
+        let relative_path = Key::ron_path(&key_id);
+
+        Task::future(async move {
+            let result = save_ron(&relative_path, &key);
+            cosmic::action::app(Message::KeyEditor(KeyEditorMessage::Saved(result)))
+        })
+    }
+}
@@ -0,0 +1,89 @@
+// handler for the keypad editor messages - edits go straight into the
+// draft, saving writes keypad_definitions/<id>.ron into the user data root
+
+use cosmic::app::Task;
+use cosmic::iced::window;
+
+use crate::app::Message;
+use crate::app::UiModel;
+use crate::data::Keypad;
+use crate::data::KeypadRef;
+use crate::data::helper::save_ron;
+use crate::editor::KeypadEditor;
+use crate::editor::KeypadEditorMessage;
+use crate::editor::helper::valid_id;
+
+impl KeypadEditor {
+    pub fn update(&mut self, message: KeypadEditorMessage, ui: &UiModel) -> Task<Message> {
+        use KeypadEditorMessage::*;
+
+        match message {
+            Opened(_) | PreviewPressed => {}
+
+            Open(index) => {
+                let keypad_view = self
+                    .keypad_refs
+                    .get(index)
+                    .filter(|keypad_ref| ui.keypads.contains(keypad_ref))
+                    .map(|keypad_ref| ui.keypads.get(keypad_ref).clone());
+                self.start(keypad_view, ui);
+            }
+            New => self.start(None, ui),
+
+            SetId(id) => {
+                self.id = id;
+                self.refresh();
+            }
+            SetLabel(label) => self.label = label,
+            SetTooltip(tooltip) => self.tooltip = tooltip,
+            SetRows(rows) => {
+                self.rows = rows;
+                self.refresh();
+            }
+            SetColumns(columns) => {
+                self.columns = columns;
+                self.refresh();
+            }
+
+            SelectCell(position) => self.selected_cell = Some(position),
+            SelectKey(index) => self.selected_key = Some(index),
+            PlaceKey => self.place_key(),
+            PlaceSpacer => self.place_spacer(false),
+            PlaceSeparator => self.place_spacer(true),
+            RemoveKey => self.remove_key(),
+            SetRowSpan(row_span) => self.set_span(row_span.trim().parse().ok(), None),
+            SetColumnSpan(column_span) => self.set_span(None, column_span.trim().parse().ok()),
+
+            Save => return self.save(),
+            Saved(Ok(path)) => self.status = Some(format!("Saved {}", path.display())),
+            Saved(Err(error)) => self.status = Some(format!("Save failed: {}", error)),
+
+            Close => {
+                if let Some(window_id) = self.window_id.take() {
+                    return window::close(window_id);
+                }
+            }
+        }
+
+        Task::none()
+    }
+
+    fn save(&mut self) -> Task<Message> {
+        if !valid_id(&self.id) {
+            self.status = Some("The id can only use letters, digits, '_' and '-'".to_string());
+            return Task::none();
+        }
+        if !self.problems.is_empty() {
+            self.status = Some("Fix the problems listed below before saving".to_string());
+            return Task::none();
+        }
+
+        let keypad = self.keypad();
+        let relative_path = Keypad::ron_path(&KeypadRef::new(keypad.id.clone()));
+
+        Task::future(async move {
+            let result = save_ron(&relative_path, &keypad);
+            cosmic::action::app(Message::KeypadEditor(KeypadEditorMessage::Saved(result)))
+        })
+    }
+}
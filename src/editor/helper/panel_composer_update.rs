@@ -0,0 +1,109 @@
+// handler for the panel composer messages - saving writes the changed
+// panels and panel_list.ron into the user data root, and the hot reload
+// picks them up from there
+
+use cosmic::app::Task;
+use cosmic::iced::window;
+
+use crate::app::Message;
+use crate::app::UiModel;
+use crate::data::Panel;
+use crate::data::PanelList;
+use crate::data::PanelRef;
+use crate::data::helper::remove_ron;
+use crate::data::helper::save_ron;
+use crate::editor::PanelComposer;
+use crate::editor::PanelComposerMessage;
+
+impl PanelComposer {
+    pub fn update(&mut self, message: PanelComposerMessage, ui: &UiModel) -> Task<Message> {
+        use PanelComposerMessage::*;
+
+        match message {
+            Opened(_) => {}
+
+            SelectPanel(index) => self.selected_panel = Some(index),
+            NewPanel => self.new_panel(),
+            DeletePanel => self.delete_panel(),
+            MovePanelUp => self.move_panel(-1),
+            MovePanelDown => self.move_panel(1),
+
+            SetId(id) => self.edit(|panel| panel.id = id),
+            SetLabel(label) => self.edit(|panel| panel.label = label),
+            SetTooltip(tooltip) => self.edit(|panel| panel.tooltip_text = tooltip),
+            SetRows(rows) => {
+                if let Ok(rows) = rows.trim().parse() {
+                    self.edit(|panel| panel.rows = rows);
+                }
+            }
+            SetColumns(columns) => {
+                if let Ok(columns) = columns.trim().parse() {
+                    self.edit(|panel| panel.columns = columns);
+                }
+            }
+
+            SelectKeypad(index) => self.selected_keypad = Some(index),
+            AddKeypad => self.add_keypad(),
+            RemoveKeypad(index) => self.edit(|panel| {
+                if index < panel.keypads.len() {
+                    panel.keypads.remove(index);
+                }
+            }),
+            SetKeypadRow(index, row) => self.place_keypad(index, row.trim().parse().ok(), None),
+            SetKeypadColumn(index, column) => {
+                self.place_keypad(index, None, column.trim().parse().ok())
+            }
+
+            Save => return self.save(ui),
+            Saved(Ok(path)) => {
+                // what is on disk now is the starting point for the next save
+                let selected_panel = self.selected_panel;
+                self.start(ui);
+                self.selected_panel = selected_panel.filter(|index| *index < self.drafts.len());
+                self.status = Some(format!("Saved {}", path.display()));
+            }
+            Saved(Err(error)) => self.status = Some(format!("Save failed: {}", error)),
+
+            Close => {
+                if let Some(window_id) = self.window_id.take() {
+                    return window::close(window_id);
+                }
+            }
+        }
+
+        Task::none()
+    }
+
+    fn save(&mut self, ui: &UiModel) -> Task<Message> {
+        if let Some(problem) = self.problem(ui) {
+            self.status = Some(problem);
+            return Task::none();
+        }
+
+        let changed: Vec<Panel> = self
+            .drafts
+            .iter()
+            .filter(|draft| draft.changed)
+            .map(|draft| draft.panel.clone())
+            .collect();
+        let stale_ids = self.stale_ids();
+        let panel_list = self.panel_list();
+
+        Task::future(async move {
+            let result = (|| {
+                for panel in &changed {
+                    let panel_ref = PanelRef {
+                        id: panel.id.clone(),
+                    };
+                    save_ron(&Panel::ron_path(&panel_ref), panel)?;
+                }
+                for id in stale_ids {
+                    remove_ron(&Panel::ron_path(&PanelRef { id }))?;
+                }
+                save_ron(&PanelList::ron_path(), &panel_list)
+            })();
+
+            cosmic::action::app(Message::PanelComposer(PanelComposerMessage::Saved(result)))
+        })
+    }
+}
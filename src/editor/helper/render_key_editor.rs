@@ -0,0 +1,146 @@
+// render the key editor window - the KeyBase fields, then the fields for
+// the chosen KeyType, then the scratch sample and what the key does to it
+
+use cosmic::Element;
+use cosmic::iced::Length;
+use cosmic::widget::{button, column, dropdown, row, scrollable, text, text_input};
+
+use crate::app::Message;
+use crate::calc::DEFAULT_REGISTER;
+use crate::calc::OPERATIONS;
+use crate::editor::ARITY_NAMES;
+use crate::editor::KeyEditor;
+use crate::editor::KeyEditorMessage;
+use crate::editor::KeyTypeKind;
+
+impl KeyEditor {
+    pub fn view(&self) -> Element<'_, Message> {
+        let edit = Message::KeyEditor;
+
+        let open_row = row::with_capacity(3)
+            .spacing(10)
+            .push(text("Open"))
+            .push(dropdown(self.key_names.as_slice(), None, move |index| {
+                edit(KeyEditorMessage::Open(index))
+            }))
+            .push(button::standard("New key").on_press(edit(KeyEditorMessage::New)));
+
+        let base_fields = row::with_capacity(4)
+            .spacing(10)
+            .push(
+                text_input("library", &self.library)
+                    .on_input(move |library| edit(KeyEditorMessage::SetLibrary(library))),
+            )
+            .push(text_input("id", &self.id).on_input(move |id| edit(KeyEditorMessage::SetId(id))))
+            .push(
+                text_input("label", &self.label)
+                    .on_input(move |label| edit(KeyEditorMessage::SetLabel(label))),
+            )
+            .push(
+                text_input("tooltip", &self.tooltip)
+                    .on_input(move |tooltip| edit(KeyEditorMessage::SetTooltip(tooltip))),
+            );
+
+        let selected_kind = KeyTypeKind::ALL.iter().position(|kind| *kind == self.kind);
+        let kind_row = row::with_capacity(2)
+            .spacing(10)
+            .push(text("Key type"))
+            .push(dropdown(&KeyTypeKind::NAMES, selected_kind, move |index| {
+                edit(KeyEditorMessage::SelectKind(index))
+            }));
+
+        let mut content = column::with_capacity(10)
+            .spacing(15)
+            .padding(20)
+            .push(text::title4("Key editor"))
+            .push(open_row)
+            .push(base_fields)
+            .push(kind_row)
+            .push(self.render_key_type_fields());
+
+        if let Some(problem) = &self.problem {
+            content = content.push(text(problem.clone()));
+        }
+
+        content = content.push(
+            row::with_capacity(2)
+                .spacing(10)
+                .push(text("Try it on"))
+                .push(
+                    text_input("sample values", &self.sample)
+                        .width(Length::Fixed(200.0))
+                        .on_input(move |sample| edit(KeyEditorMessage::SetSample(sample))),
+                ),
+        );
+
+        if let Some(preview) = &self.preview {
+            content = content.push(text::heading(preview.clone()));
+        }
+
+        if let Some(status) = &self.status {
+            content = content.push(text(status.clone()));
+        }
+
+        content = content.push(
+            row::with_capacity(2)
+                .spacing(10)
+                .push(button::suggested("Save").on_press(edit(KeyEditorMessage::Save)))
+                .push(button::standard("Close").on_press(edit(KeyEditorMessage::Close))),
+        );
+
+        scrollable(content).into()
+    }
+
+    fn render_key_type_fields(&self) -> Element<'_, Message> {
+        let edit = Message::KeyEditor;
+
+        match self.kind {
+            KeyTypeKind::Operand => row::with_capacity(2)
+                .spacing(10)
+                .push(text("Value"))
+                .push(
+                    text_input("0", &self.value)
+                        .on_input(move |value| edit(KeyEditorMessage::SetValue(value))),
+                )
+                .into(),
+
+            KeyTypeKind::Operator => {
+                let mut fields = row::with_capacity(4)
+                    .spacing(10)
+                    .push(
+                        text_input("operator template, e.g. {x} * 1.1", &self.qalc_term)
+                            .on_input(move |qalc_term| edit(KeyEditorMessage::SetTerm(qalc_term))),
+                    )
+                    .push(dropdown(&ARITY_NAMES, Some(self.arity), move |index| {
+                        edit(KeyEditorMessage::SelectArity(index))
+                    }));
+
+                if self.arity == ARITY_NAMES.len() - 1 {
+                    fields = fields.push(text("operands")).push(
+                        text_input("3", &self.operands)
+                            .width(Length::Fixed(50.0))
+                            .on_input(move |operands| {
+                                edit(KeyEditorMessage::SetOperands(operands))
+                            }),
+                    );
+                }
+
+                fields.into()
+            }
+
+            // the register only matters to the MEMORY_ operations
+            KeyTypeKind::Internal => row::with_capacity(4)
+                .spacing(10)
+                .push(text("Operation"))
+                .push(dropdown(&OPERATIONS, Some(self.operation), move |index| {
+                    edit(KeyEditorMessage::SelectOperation(index))
+                }))
+                .push(text("Register"))
+                .push(
+                    text_input(DEFAULT_REGISTER, &self.register)
+                        .on_input(move |register| edit(KeyEditorMessage::SetRegister(register))),
+                )
+                .into(),
+        }
+    }
+}
@@ -0,0 +1,174 @@
+// render the keypad editor window - the keypad fields across the top, the
+// cell picker and key controls in the middle, then the live preview built
+// by build_button_grid and whatever is wrong with the draft
+
+use cosmic::Element;
+use cosmic::iced::Length;
+use cosmic::widget::{button, column, dropdown, row, scrollable, text, text_input};
+
+use crate::app::Message;
+use crate::editor::KeypadEditor;
+use crate::editor::KeypadEditorMessage;
+use crate::ui::GridPosition;
+use crate::ui::helper::build_button_grid;
+
+impl KeypadEditor {
+    pub fn view(&self) -> Element<'_, Message> {
+        let edit = Message::KeypadEditor;
+
+        let open_row = row::with_capacity(3)
+            .spacing(10)
+            .push(text("Open"))
+            .push(dropdown(self.keypad_names.as_slice(), None, |index| {
+                edit(KeypadEditorMessage::Open(index))
+            }))
+            .push(button::standard("New keypad").on_press(edit(KeypadEditorMessage::New)));
+
+        let fields = row::with_capacity(5)
+            .spacing(10)
+            .push(text_input("id", &self.id).on_input(|id| edit(KeypadEditorMessage::SetId(id))))
+            .push(
+                text_input("label", &self.label)
+                    .on_input(|label| edit(KeypadEditorMessage::SetLabel(label))),
+            )
+            .push(
+                text_input("tooltip", &self.tooltip)
+                    .on_input(|tooltip| edit(KeypadEditorMessage::SetTooltip(tooltip))),
+            )
+            .push(
+                text_input("rows", &self.rows)
+                    .width(Length::Fixed(60.0))
+                    .on_input(|rows| edit(KeypadEditorMessage::SetRows(rows))),
+            )
+            .push(
+                text_input("columns", &self.columns)
+                    .width(Length::Fixed(60.0))
+                    .on_input(|columns| edit(KeypadEditorMessage::SetColumns(columns))),
+            );
+
+        let preview = build_button_grid(&self.keygrids, &self.keypad_view())
+            .map(|_| Message::KeypadEditor(KeypadEditorMessage::PreviewPressed));
+
+        let mut problems = column::with_capacity(self.problems.len()).spacing(5);
+        for problem in &self.problems {
+            problems = problems.push(text::caption(problem.kind.to_string()));
+        }
+
+        let mut content = column::with_capacity(8)
+            .spacing(15)
+            .padding(20)
+            .push(text::title4("Keypad editor"))
+            .push(open_row)
+            .push(fields)
+            .push(
+                row::with_capacity(2)
+                    .spacing(20)
+                    .push(self.render_cell_picker())
+                    .push(self.render_key_controls()),
+            )
+            .push(text::heading("Preview"))
+            .push(preview)
+            .push(problems);
+
+        if let Some(status) = &self.status {
+            content = content.push(text(status.clone()));
+        }
+
+        content = content.push(
+            row::with_capacity(2)
+                .spacing(10)
+                .push(button::suggested("Save").on_press(edit(KeypadEditorMessage::Save)))
+                .push(button::standard("Close").on_press(edit(KeypadEditorMessage::Close))),
+        );
+
+        scrollable(content).into()
+    }
+
+    // one small button per cell, labelled with the key that starts there
+    fn render_cell_picker(&self) -> Element<'_, Message> {
+        let mut grid = column::with_capacity(self.rows()).spacing(4);
+
+        for grid_row in 1..=self.rows() {
+            let mut cells = row::with_capacity(self.columns()).spacing(4);
+
+            for grid_column in 1..=self.columns() {
+                let position = GridPosition::new(grid_row, grid_column);
+                let label = match (self.key_at(position), self.spacer_at(position)) {
+                    (Some(key), _) => key.label.clone(),
+                    (None, Some(spacer_ref)) if spacer_ref.is_separator() => "—".to_string(),
+                    (None, Some(_)) => "□".to_string(),
+                    (None, None) => "·".to_string(),
+                };
+
+                let cell = match self.selected_cell == Some(position) {
+                    true => button::suggested(label),
+                    false => button::standard(label),
+                };
+                cells = cells.push(cell.width(Length::Fixed(60.0)).on_press(
+                    Message::KeypadEditor(KeypadEditorMessage::SelectCell(position)),
+                ));
+            }
+
+            grid = grid.push(cells);
+        }
+
+        grid.into()
+    }
+
+    fn render_key_controls(&self) -> Element<'_, Message> {
+        let edit = Message::KeypadEditor;
+
+        let mut controls = column::with_capacity(5).spacing(10);
+
+        let Some(cell) = self.selected_cell else {
+            return controls.push(text("Pick a cell to place a key")).into();
+        };
+
+        controls = controls
+            .push(text::heading(format!("Cell at {}", cell)))
+            .push(dropdown(
+                self.key_names.as_slice(),
+                self.selected_key,
+                |index| edit(KeypadEditorMessage::SelectKey(index)),
+            ))
+            .push(
+                row::with_capacity(4)
+                    .spacing(10)
+                    .push(
+                        button::standard("Place key").on_press(edit(KeypadEditorMessage::PlaceKey)),
+                    )
+                    .push(
+                        button::standard("Spacer").on_press(edit(KeypadEditorMessage::PlaceSpacer)),
+                    )
+                    .push(
+                        button::standard("Separator")
+                            .on_press(edit(KeypadEditorMessage::PlaceSeparator)),
+                    )
+                    .push(
+                        button::destructive("Clear cell")
+                            .on_press(edit(KeypadEditorMessage::RemoveKey)),
+                    ),
+            );
+
+        if let Some(key_ref) = self.selected_key_ref() {
+            controls = controls.push(
+                row::with_capacity(4)
+                    .spacing(10)
+                    .push(text("Rows spanned"))
+                    .push(
+                        text_input("1", key_ref.grid_span.row_span.to_string())
+                            .width(Length::Fixed(50.0))
+                            .on_input(|span| edit(KeypadEditorMessage::SetRowSpan(span))),
+                    )
+                    .push(text("Columns spanned"))
+                    .push(
+                        text_input("1", key_ref.grid_span.column_span.to_string())
+                            .width(Length::Fixed(50.0))
+                            .on_input(|span| edit(KeypadEditorMessage::SetColumnSpan(span))),
+                    ),
+            );
+        }
+
+        controls.into()
+    }
+}
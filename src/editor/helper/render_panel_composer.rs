@@ -0,0 +1,169 @@
+// render the panel composer window - the panels in navbar order down the
+// left, and the selected panel's fields, grid size and keypad cells on the right
+
+use cosmic::Element;
+use cosmic::iced::Length;
+use cosmic::widget::{button, column, dropdown, row, scrollable, text, text_input};
+
+use crate::app::Message;
+use crate::editor::PanelComposer;
+use crate::editor::PanelComposerMessage;
+
+impl PanelComposer {
+    pub fn view(&self) -> Element<'_, Message> {
+        let edit = Message::PanelComposer;
+
+        let mut content = column::with_capacity(5)
+            .spacing(15)
+            .padding(20)
+            .push(text::title4("Panel composer"))
+            .push(
+                row::with_capacity(2)
+                    .spacing(30)
+                    .push(self.render_panel_list())
+                    .push(self.render_panel_fields()),
+            );
+
+        if let Some(status) = &self.status {
+            content = content.push(text(status.clone()));
+        }
+
+        content = content.push(
+            row::with_capacity(2)
+                .spacing(10)
+                .push(button::suggested("Save").on_press(edit(PanelComposerMessage::Save)))
+                .push(button::standard("Close").on_press(edit(PanelComposerMessage::Close))),
+        );
+
+        scrollable(content).into()
+    }
+
+    fn render_panel_list(&self) -> Element<'_, Message> {
+        let edit = Message::PanelComposer;
+
+        let mut panels = column::with_capacity(self.drafts.len() + 2)
+            .spacing(5)
+            .width(Length::Fixed(220.0))
+            .push(text::heading("Panels"));
+
+        for (index, name) in self.panel_names().into_iter().enumerate() {
+            let panel = match self.selected_panel == Some(index) {
+                true => button::suggested(name),
+                false => button::standard(name),
+            };
+            panels = panels.push(
+                panel
+                    .width(Length::Fill)
+                    .on_press(edit(PanelComposerMessage::SelectPanel(index))),
+            );
+        }
+
+        panels
+            .push(
+                row::with_capacity(4)
+                    .spacing(5)
+                    .push(button::standard("New").on_press(edit(PanelComposerMessage::NewPanel)))
+                    .push(button::standard("Up").on_press(edit(PanelComposerMessage::MovePanelUp)))
+                    .push(
+                        button::standard("Down")
+                            .on_press(edit(PanelComposerMessage::MovePanelDown)),
+                    )
+                    .push(
+                        button::destructive("Delete")
+                            .on_press(edit(PanelComposerMessage::DeletePanel)),
+                    ),
+            )
+            .into()
+    }
+
+    fn render_panel_fields(&self) -> Element<'_, Message> {
+        let edit = Message::PanelComposer;
+
+        let Some(draft) = self.selected() else {
+            return text("Pick a panel or add a new one").into();
+        };
+        let panel = &draft.panel;
+
+        let mut fields =
+            column::with_capacity(panel.keypads.len() + 5)
+                .spacing(10)
+                .push(
+                    row::with_capacity(3)
+                        .spacing(10)
+                        .push(
+                            text_input("id", &panel.id)
+                                .on_input(move |id| edit(PanelComposerMessage::SetId(id))),
+                        )
+                        .push(
+                            text_input("label", &panel.label)
+                                .on_input(move |label| edit(PanelComposerMessage::SetLabel(label))),
+                        )
+                        .push(text_input("tooltip", &panel.tooltip_text).on_input(
+                            move |tooltip| edit(PanelComposerMessage::SetTooltip(tooltip)),
+                        )),
+                )
+                .push(
+                    row::with_capacity(4)
+                        .spacing(10)
+                        .push(text("Rows"))
+                        .push(
+                            text_input("1", panel.rows.to_string())
+                                .width(Length::Fixed(50.0))
+                                .on_input(move |rows| edit(PanelComposerMessage::SetRows(rows))),
+                        )
+                        .push(text("Columns"))
+                        .push(
+                            text_input("1", panel.columns.to_string())
+                                .width(Length::Fixed(50.0))
+                                .on_input(move |columns| {
+                                    edit(PanelComposerMessage::SetColumns(columns))
+                                }),
+                        ),
+                )
+                .push(text::heading("Keypads"));
+
+        for (index, keypad_ref) in panel.keypads.iter().enumerate() {
+            fields = fields.push(
+                row::with_capacity(6)
+                    .spacing(10)
+                    .push(text(self.keypad_label(keypad_ref)).width(Length::Fixed(200.0)))
+                    .push(text("Row"))
+                    .push(
+                        text_input("1", keypad_ref.row.to_string())
+                            .width(Length::Fixed(50.0))
+                            .on_input(move |row| {
+                                edit(PanelComposerMessage::SetKeypadRow(index, row))
+                            }),
+                    )
+                    .push(text("Column"))
+                    .push(
+                        text_input("1", keypad_ref.column.to_string())
+                            .width(Length::Fixed(50.0))
+                            .on_input(move |column| {
+                                edit(PanelComposerMessage::SetKeypadColumn(index, column))
+                            }),
+                    )
+                    .push(
+                        button::destructive("Remove")
+                            .on_press(edit(PanelComposerMessage::RemoveKeypad(index))),
+                    ),
+            );
+        }
+
+        fields
+            .push(
+                row::with_capacity(2)
+                    .spacing(10)
+                    .push(dropdown(
+                        self.keypad_names.as_slice(),
+                        self.selected_keypad,
+                        move |index| edit(PanelComposerMessage::SelectKeypad(index)),
+                    ))
+                    .push(
+                        button::standard("Add keypad")
+                            .on_press(edit(PanelComposerMessage::AddKeypad)),
+                    ),
+            )
+            .into()
+    }
+}
@@ -0,0 +1,9 @@
+// ids become file names, so keep them to something every file system and
+// the key id syntax (library/id) is happy with
+
+pub fn valid_id(id: &str) -> bool {
+    !id.is_empty()
+        && id
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '_' || c == '-')
+}
@@ -0,0 +1,18 @@
+mod key_editor;
+mod key_editor_message;
+mod key_type_kind;
+mod keypad_editor;
+mod keypad_editor_message;
+mod panel_composer;
+mod panel_composer_message;
+mod panel_draft;
+
+pub use key_editor::ARITY_NAMES;
+pub use key_editor::KeyEditor;
+pub use key_editor_message::KeyEditorMessage;
+pub use key_type_kind::KeyTypeKind;
+pub use keypad_editor::KeypadEditor;
+pub use keypad_editor_message::KeypadEditorMessage;
+pub use panel_composer::PanelComposer;
+pub use panel_composer_message::PanelComposerMessage;
+pub use panel_draft::PanelDraft;
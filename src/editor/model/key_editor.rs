@@ -0,0 +1,259 @@
+// the key editor - a draft Key being put together in its own window
+//
+// the fields are kept as typed and turned into a KeyType on every change,
+// so a bad operator template is reported as you type. the scratch sample
+// is run through a CalcState exactly as if the key was pressed on it
+
+use cosmic::iced::window;
+use rust_decimal::Decimal;
+
+use crate::calc::Arity;
+use crate::calc::CalcState;
+use crate::calc::OPERATIONS;
+use crate::calc::TermTemplate;
+use crate::calc::helper::check_term;
+use crate::calc::helper::resolve_term;
+use crate::config::Config;
+use crate::data::Key;
+use crate::data::KeyBase;
+use crate::data::KeyType;
+use crate::data::helper::list_key_ids;
+use crate::editor::KeyTypeKind;
+use crate::ui::KeyId;
+
+// the arity dropdown - the last one takes its operand count from the form
+pub const ARITY_NAMES: [&str; 4] = ["prefix unary", "postfix unary", "infix binary", "n-ary"];
+
+#[derive(Debug, Default, Clone)]
+pub struct KeyEditor {
+    pub window_id: Option<window::Id>,
+
+    // every key in the library, to open one as a starting point
+    pub key_ids: Vec<KeyId>,
+    pub key_names: Vec<String>,
+
+    pub library: String,
+    pub id: String,
+    pub label: String,
+    pub tooltip: String,
+    pub kind: KeyTypeKind,
+
+    pub value: String,
+    pub qalc_term: String,
+    pub arity: usize,
+    pub operands: String,
+    pub operation: usize,
+    pub register: String,
+
+    pub sample: String,
+    pub problem: Option<String>,
+    pub preview: Option<String>,
+    // a preview for an engine that runs another program is worked out off
+    // the UI thread, only the latest one asked for is shown
+    pub pending_preview: Option<(KeyType, Vec<Decimal>)>,
+    pub preview_generation: u64,
+    pub status: Option<String>,
+}
+
+impl KeyEditor {
+    // start over - from an existing key or a blank operator
+    pub fn start(&mut self, key: Option<(KeyId, Key)>, config: &Config) {
+        let window_id = self.window_id;
+        let preview_generation = self.preview_generation;
+        *self = Self::default();
+        self.window_id = window_id;
+        self.preview_generation = preview_generation;
+
+        self.key_ids = list_key_ids();
+        self.key_names = self.key_ids.iter().map(KeyId::to_string).collect();
+        self.sample = "100".to_string();
+        self.operands = "3".to_string();
+
+        if let Some((key_id, key)) = key {
+            self.library = key_id.library;
+            self.id = key_id.id;
+            self.label = key.label.clone();
+            self.tooltip = key.tooltip_text.clone();
+
+            match key.key_type() {
+                KeyType::Operand { value } => {
+                    self.kind = KeyTypeKind::Operand;
+                    self.value = value.to_string();
+                }
+                KeyType::Operator { qalc_term, arity } => {
+                    self.kind = KeyTypeKind::Operator;
+                    self.qalc_term = qalc_term.to_string();
+                    self.arity = match arity {
+                        Arity::PrefixUnary => 0,
+                        Arity::PostfixUnary => 1,
+                        Arity::InfixBinary => 2,
+                        Arity::Nary(count) => {
+                            self.operands = count.to_string();
+                            3
+                        }
+                    };
+                }
+                KeyType::Internal {
+                    operation,
+                    register,
+                } => {
+                    self.kind = KeyTypeKind::Internal;
+                    self.operation = OPERATIONS
+                        .iter()
+                        .position(|known| known == operation)
+                        .unwrap_or_default();
+                    self.register = register.clone();
+                }
+            }
+        }
+
+        self.refresh(config);
+    }
+
+    pub fn key_id(&self) -> KeyId {
+        KeyId::new(self.library.trim(), self.id.trim())
+    }
+
+    pub fn arity(&self) -> Arity {
+        match self.arity {
+            0 => Arity::PrefixUnary,
+            1 => Arity::PostfixUnary,
+            2 => Arity::InfixBinary,
+            _ => Arity::Nary(self.operands.trim().parse().unwrap_or_default()),
+        }
+    }
+
+    // the KeyType the form describes, or what's wrong with it
+    pub fn key_type(&self) -> Result<KeyType, String> {
+        match self.kind {
+            KeyTypeKind::Operand => self
+                .value
+                .trim()
+                .parse::<Decimal>()
+                .map(|value| KeyType::Operand { value })
+                .map_err(|_| format!("'{}' isn't a number", self.value)),
+
+            KeyTypeKind::Operator => {
+                let qalc_term =
+                    TermTemplate::try_from(self.qalc_term.clone()).map_err(|e| e.to_string())?;
+                let arity = self.arity();
+                check_term(&qalc_term, arity).map_err(|e| e.to_string())?;
+                Ok(KeyType::Operator { qalc_term, arity })
+            }
+
+            KeyTypeKind::Internal => match OPERATIONS.get(self.operation) {
+                None => Err("An internal key needs an operation".to_string()),
+                Some(operation) => Ok(KeyType::Internal {
+                    operation: operation.to_string(),
+                    register: self.register.trim().to_string(),
+                }),
+            },
+        }
+    }
+
+    pub fn key(&self) -> Result<Key, String> {
+        let base = KeyBase {
+            id: self.id.trim().to_string(),
+            label: self.label.clone(),
+            tooltip_text: self.tooltip.clone(),
+        };
+        self.key_type().map(|key_type| Key::new(base, key_type))
+    }
+
+    // re-check the form and work out the preview
+    pub fn refresh(&mut self, config: &Config) {
+        self.preview = None;
+        self.pending_preview = None;
+
+        let key_type = match self.key_type() {
+            Ok(key_type) => key_type,
+            Err(problem) => {
+                self.problem = Some(problem);
+                return;
+            }
+        };
+        self.problem = None;
+
+        let samples: Result<Vec<Decimal>, _> = self
+            .sample
+            .split([' ', ','])
+            .filter(|sample| !sample.is_empty())
+            .map(str::parse::<Decimal>)
+            .collect();
+        let Ok(samples) = samples else {
+            self.preview = Some("The sample has to be one or more numbers".to_string());
+            return;
+        };
+
+        if config.engine.engine().is_blocking() {
+            self.preview = Some("Working it out…".to_string());
+            self.pending_preview = Some((key_type, samples));
+        } else {
+            self.preview = Some(preview(&key_type, &samples, config));
+        }
+    }
+
+    // hand over the preview still to be worked out, with its generation
+    pub fn take_pending_preview(
+        &mut self,
+        config: &Config,
+    ) -> Option<(u64, impl FnOnce() -> String + Send + 'static)> {
+        let (key_type, samples) = self.pending_preview.take()?;
+        self.preview_generation += 1;
+        let config = config.clone();
+        Some((self.preview_generation, move || {
+            preview(&key_type, &samples, &config)
+        }))
+    }
+}
+
+// press the key on the sample - the first sample is the entry, any more
+// are fed in as the remaining operands with equals
+fn preview(key_type: &KeyType, samples: &[Decimal], config: &Config) -> String {
+    let Some((first, rest)) = samples.split_first() else {
+        return "Enter a sample value to try the key on".to_string();
+    };
+
+    let expression = match key_type {
+        KeyType::Operator { qalc_term, arity } => match samples.get(..arity.operands()) {
+            Some(operands) => resolve_term(qalc_term, *arity, operands, &answers(key_type)).ok(),
+            None => {
+                return format!(
+                    "A {} operator needs {} sample values",
+                    arity,
+                    arity.operands()
+                );
+            }
+        },
+        _ => None,
+    };
+
+    let mut calc = CalcState::with_precision(config.precision).with_engine(config.engine.engine());
+    calc.set_answers(answers(key_type));
+    calc.enter(*first);
+    calc.press(key_type);
+
+    if let KeyType::Operator { arity, .. } = key_type {
+        let equals = KeyType::Internal {
+            operation: "EQUALS".to_string(),
+            register: String::new(),
+        };
+        for sample in rest.iter().take(arity.operands().saturating_sub(1)) {
+            calc.enter(*sample);
+            calc.press(&equals);
+        }
+    }
+
+    match expression {
+        Some(expression) => format!("{} = {}", expression.trim(), calc.display()),
+        None => format!("{} → {}", first, calc.display()),
+    }
+}
+
+// there's no tape in the editor, past answers are all taken as 1
+fn answers(key_type: &KeyType) -> Vec<Decimal> {
+    match key_type {
+        KeyType::Operator { qalc_term, .. } => vec![Decimal::ONE; qalc_term.deepest_answer()],
+        _ => Vec::new(),
+    }
+}
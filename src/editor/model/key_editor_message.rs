@@ -0,0 +1,33 @@
+// everything that can happen in the key editor window
+
+use cosmic::iced::window;
+use std::path::PathBuf;
+
+use crate::data::DataError;
+
+#[derive(Debug, Clone)]
+pub enum KeyEditorMessage {
+    Opened(window::Id),
+    Open(usize),
+    New,
+
+    SetLibrary(String),
+    SetId(String),
+    SetLabel(String),
+    SetTooltip(String),
+    SelectKind(usize),
+
+    SetValue(String),
+    SetTerm(String),
+    SelectArity(usize),
+    SetOperands(String),
+    SelectOperation(usize),
+    SetRegister(String),
+
+    SetSample(String),
+    Previewed(u64, String),
+
+    Save,
+    Saved(Result<PathBuf, DataError>),
+    Close,
+}
@@ -0,0 +1,27 @@
+// which KeyType variant the key editor is building - the dropdown picks
+// one of these and the form shows that variant's fields
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum KeyTypeKind {
+    Operand,
+    #[default]
+    Operator,
+    Internal,
+}
+
+impl KeyTypeKind {
+    pub const ALL: [KeyTypeKind; 3] = [Self::Operand, Self::Operator, Self::Internal];
+    pub const NAMES: [&'static str; 3] = [
+        Self::Operand.as_str(),
+        Self::Operator.as_str(),
+        Self::Internal.as_str(),
+    ];
+
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Operand => "Operand",
+            Self::Operator => "Operator",
+            Self::Internal => "Internal",
+        }
+    }
+}
@@ -0,0 +1,265 @@
+// the keypad editor - a draft Keypad being put together in its own window
+//
+// the text fields are kept as typed so a half-entered number doesn't get
+// thrown away, and every change rebuilds the preview keygrids and runs the
+// draft through the same validation as the library
+
+use cosmic::iced::window;
+
+use crate::app::UiModel;
+use crate::data::DataError;
+use crate::data::DataErrorKind;
+use crate::data::DataSource;
+use crate::data::Key;
+use crate::data::KeyRef;
+use crate::data::Keypad;
+use crate::data::KeypadRef;
+use crate::data::SpacerRef;
+use crate::data::ValidationReport;
+use crate::data::helper::list_key_ids;
+use crate::ui::GridPosition;
+use crate::ui::GridSpan;
+use crate::ui::KeyGrids;
+use crate::ui::KeyId;
+use crate::ui::KeypadView;
+use crate::ui::Keys;
+
+// the most rows or columns the editor will lay out
+pub const MAX_GRID: usize = 12;
+
+#[derive(Debug, Default, Clone)]
+pub struct KeypadEditor {
+    pub window_id: Option<window::Id>,
+
+    pub id: String,
+    pub label: String,
+    pub tooltip: String,
+    pub rows: String,
+    pub columns: String,
+    pub keys: Vec<KeyRef>,
+    pub spacers: Vec<SpacerRef>,
+
+    // the loaded keypads that can be opened for editing
+    pub keypad_refs: Vec<KeypadRef>,
+    pub keypad_names: Vec<String>,
+
+    // every key in the library and its dropdown label
+    pub key_ids: Vec<KeyId>,
+    pub key_names: Vec<String>,
+    pub selected_key: Option<usize>,
+    pub selected_cell: Option<GridPosition>,
+
+    // definitions of the placed keys, for the preview
+    pub loaded_keys: Keys,
+    pub keygrids: KeyGrids,
+    pub problems: ValidationReport,
+    pub status: Option<String>,
+}
+
+impl KeypadEditor {
+    // start over - from an existing keypad or a blank 4x4 one
+    pub fn start(&mut self, keypad: Option<KeypadView>, ui: &UiModel) {
+        let window_id = self.window_id;
+        *self = Self::default();
+        self.window_id = window_id;
+
+        self.key_ids = list_key_ids();
+        self.key_names = self.key_ids.iter().map(KeyId::to_string).collect();
+        for (keypad_ref, keypad_view) in &ui.keypads {
+            self.keypad_refs.push(keypad_ref.clone());
+            self.keypad_names.push(keypad_view.label.clone());
+        }
+
+        match keypad {
+            Some(keypad_view) => {
+                self.id = keypad_view.id.id;
+                self.label = keypad_view.label;
+                self.tooltip = keypad_view.tooltip;
+                self.rows = keypad_view.rows.to_string();
+                self.columns = keypad_view.columns.to_string();
+                self.keys = keypad_view.keys;
+                self.spacers = keypad_view.spacers;
+            }
+            None => {
+                self.rows = "4".to_string();
+                self.columns = "4".to_string();
+            }
+        }
+
+        for key_id in self
+            .keys
+            .iter()
+            .map(|key_ref| key_ref.key_id.clone())
+            .collect::<Vec<_>>()
+        {
+            self.load_key(&key_id);
+        }
+        self.refresh();
+    }
+
+    // kept to 1..=MAX_GRID, refresh reports anything bigger as a problem
+    pub fn rows(&self) -> usize {
+        typed_size(&self.rows).clamp(1, MAX_GRID)
+    }
+
+    pub fn columns(&self) -> usize {
+        typed_size(&self.columns).clamp(1, MAX_GRID)
+    }
+
+    pub fn keypad(&self) -> Keypad {
+        Keypad {
+            id: self.id.clone(),
+            label: self.label.clone(),
+            tooltip: self.tooltip.clone(),
+            rows: self.rows(),
+            columns: self.columns(),
+            keys: self.keys.clone(),
+            spacers: self.spacers.clone(),
+            source: DataSource::default(),
+        }
+    }
+
+    pub fn keypad_view(&self) -> KeypadView {
+        KeypadView::from(self.keypad())
+    }
+
+    // the key whose top left corner is the selected cell
+    pub fn selected_key_ref(&self) -> Option<&KeyRef> {
+        let cell = self.selected_cell?;
+        self.keys
+            .iter()
+            .find(|key_ref| key_ref.grid_position == cell)
+    }
+
+    pub fn spacer_at(&self, position: GridPosition) -> Option<&SpacerRef> {
+        self.spacers
+            .iter()
+            .find(|spacer_ref| spacer_ref.grid_position() == position)
+    }
+
+    pub fn key_at(&self, position: GridPosition) -> Option<&Key> {
+        self.keys
+            .iter()
+            .find(|key_ref| key_ref.grid_position == position)
+            .and_then(|key_ref| self.loaded_keys.get(&key_ref.key_id))
+    }
+
+    pub fn place_key(&mut self) {
+        let (Some(cell), Some(key_id)) = (
+            self.selected_cell,
+            self.selected_key
+                .and_then(|index| self.key_ids.get(index))
+                .cloned(),
+        ) else {
+            return;
+        };
+
+        let grid_span = self
+            .selected_key_ref()
+            .map(|key_ref| key_ref.grid_span)
+            .unwrap_or_default();
+        self.keys.retain(|key_ref| key_ref.grid_position != cell);
+        self.spacers
+            .retain(|spacer_ref| spacer_ref.grid_position() != cell);
+        self.keys.push(KeyRef {
+            key_id: key_id.clone(),
+            grid_position: cell,
+            grid_span,
+        });
+        self.keys
+            .sort_by_key(|key_ref| (key_ref.grid_position.row, key_ref.grid_position.column));
+
+        self.load_key(&key_id);
+        self.refresh();
+    }
+
+    // a spacer or separator takes the cell instead of a key
+    pub fn place_spacer(&mut self, separator: bool) {
+        let Some(grid_position) = self.selected_cell else {
+            return;
+        };
+
+        let grid_span = GridSpan::default();
+        self.keys
+            .retain(|key_ref| key_ref.grid_position != grid_position);
+        self.spacers
+            .retain(|spacer_ref| spacer_ref.grid_position() != grid_position);
+        self.spacers.push(match separator {
+            true => SpacerRef::Separator {
+                grid_position,
+                grid_span,
+            },
+            false => SpacerRef::Spacer {
+                grid_position,
+                grid_span,
+            },
+        });
+        self.refresh();
+    }
+
+    pub fn remove_key(&mut self) {
+        if let Some(cell) = self.selected_cell {
+            self.keys.retain(|key_ref| key_ref.grid_position != cell);
+            self.spacers
+                .retain(|spacer_ref| spacer_ref.grid_position() != cell);
+            self.refresh();
+        }
+    }
+
+    pub fn set_span(&mut self, row_span: Option<usize>, column_span: Option<usize>) {
+        let Some(cell) = self.selected_cell else {
+            return;
+        };
+
+        if let Some(key_ref) = self
+            .keys
+            .iter_mut()
+            .find(|key_ref| key_ref.grid_position == cell)
+        {
+            key_ref.grid_span = GridSpan {
+                row_span: row_span.unwrap_or(key_ref.grid_span.row_span),
+                column_span: column_span.unwrap_or(key_ref.grid_span.column_span),
+            };
+            self.refresh();
+        }
+    }
+
+    // rebuild the preview and re-check the draft
+    pub fn refresh(&mut self) {
+        let keypad_ref = KeypadRef::new(self.id.clone());
+
+        let mut ui = UiModel::default();
+        ui.keys = self.loaded_keys.clone();
+        ui.keypads.insert(&keypad_ref, &self.keypad_view());
+        ui.fill_keygrids();
+
+        self.problems = ui.validate_library();
+        self.keygrids = ui.keygrids;
+
+        let (rows, columns) = (typed_size(&self.rows), typed_size(&self.columns));
+        if rows > MAX_GRID || columns > MAX_GRID {
+            let kind = DataErrorKind::GridTooLarge {
+                rows,
+                columns,
+                max: MAX_GRID,
+            };
+            self.problems.push(DataError::new(kind).with_id(&self.id));
+        }
+    }
+
+    fn load_key(&mut self, key_id: &KeyId) {
+        if self.loaded_keys.contains(key_id) {
+            return;
+        }
+
+        // a key that won't load shows up as unknown in the problems
+        if let Ok(key) = Key::from_ron(key_id) {
+            self.loaded_keys.insert(key_id.clone(), key);
+        }
+    }
+}
+
+// the number as typed - anything that isn't one counts as 0
+fn typed_size(field: &str) -> usize {
+    field.trim().parse().unwrap_or_default()
+}
@@ -0,0 +1,36 @@
+// everything that can happen in the keypad editor window
+
+use cosmic::iced::window;
+use std::path::PathBuf;
+
+use crate::data::DataError;
+use crate::ui::GridPosition;
+
+#[derive(Debug, Clone)]
+pub enum KeypadEditorMessage {
+    Opened(window::Id),
+    Open(usize),
+    New,
+
+    SetId(String),
+    SetLabel(String),
+    SetTooltip(String),
+    SetRows(String),
+    SetColumns(String),
+
+    SelectCell(GridPosition),
+    SelectKey(usize),
+    PlaceKey,
+    PlaceSpacer,
+    PlaceSeparator,
+    RemoveKey,
+    SetRowSpan(String),
+    SetColumnSpan(String),
+
+    // the preview is look, don't touch
+    PreviewPressed,
+
+    Save,
+    Saved(Result<PathBuf, DataError>),
+    Close,
+}
@@ -0,0 +1,269 @@
+// the panel composer - every panel in panel_list order, put together in
+// its own window
+//
+// each keypad goes in a cell of the panel's grid, a new one lands in the
+// first free cell. panels are edited in place and only the ones that changed are written
+// out, along with a fresh panel_list.ron, when the composer is saved.
+// a deleted or renamed panel leaves its old id behind to be cleared out
+// of the user data root
+
+use cosmic::iced::window;
+
+use crate::app::UiModel;
+use crate::data::KeypadRef;
+use crate::data::Panel;
+use crate::data::PanelList;
+use crate::data::PanelRef;
+use crate::editor::PanelDraft;
+use crate::editor::helper::valid_id;
+use crate::ui::GridPosition;
+use crate::ui::PanelView;
+
+#[derive(Debug, Default, Clone)]
+pub struct PanelComposer {
+    pub window_id: Option<window::Id>,
+
+    pub drafts: Vec<PanelDraft>,
+    pub selected_panel: Option<usize>,
+    pub removed_ids: Vec<String>,
+
+    // the loaded keypads that can go on a panel
+    pub keypad_refs: Vec<KeypadRef>,
+    pub keypad_names: Vec<String>,
+    pub selected_keypad: Option<usize>,
+
+    pub status: Option<String>,
+}
+
+impl PanelComposer {
+    // start over from the panels in the library
+    pub fn start(&mut self, ui: &UiModel) {
+        let window_id = self.window_id;
+        *self = Self::default();
+        self.window_id = window_id;
+
+        for (keypad_ref, keypad_view) in &ui.keypads {
+            self.keypad_refs.push(keypad_ref.clone());
+            self.keypad_names.push(keypad_view.label.clone());
+        }
+
+        let panel_list = match PanelList::from_ron() {
+            Ok(panel_list) => panel_list,
+            Err(error) => {
+                self.status = Some(error.to_string());
+                return;
+            }
+        };
+
+        for panel_ref in &panel_list {
+            match Panel::from_ron(panel_ref) {
+                Ok(panel) => self.drafts.push(PanelDraft::loaded(panel)),
+                Err(error) => self.status = Some(error.to_string()),
+            }
+        }
+
+        self.selected_panel = (!self.drafts.is_empty()).then_some(0);
+    }
+
+    pub fn panel_names(&self) -> Vec<String> {
+        self.drafts
+            .iter()
+            .map(|draft| draft.panel.label.clone())
+            .collect()
+    }
+
+    pub fn selected(&self) -> Option<&PanelDraft> {
+        self.drafts.get(self.selected_panel?)
+    }
+
+    pub fn panel_list(&self) -> PanelList {
+        PanelList {
+            panel_refs: self
+                .drafts
+                .iter()
+                .map(|draft| PanelRef {
+                    id: draft.panel.id.clone(),
+                })
+                .collect(),
+        }
+    }
+
+    pub fn keypad_label(&self, keypad_ref: &KeypadRef) -> String {
+        self.keypad_refs
+            .iter()
+            .position(|candidate| candidate == keypad_ref)
+            .map(|index| self.keypad_names[index].clone())
+            .unwrap_or_else(|| keypad_ref.id.clone())
+    }
+
+    // edit the selected panel - anything edited gets written on save
+    pub fn edit(&mut self, edit: impl FnOnce(&mut Panel)) {
+        let Some(draft) = self
+            .selected_panel
+            .and_then(|index| self.drafts.get_mut(index))
+        else {
+            return;
+        };
+
+        edit(&mut draft.panel);
+        draft.changed = true;
+    }
+
+    pub fn new_panel(&mut self) {
+        let mut count = self.drafts.len() + 1;
+        while self
+            .drafts
+            .iter()
+            .any(|draft| draft.panel.id == format!("panel{}", count))
+        {
+            count += 1;
+        }
+
+        self.drafts.push(PanelDraft::new(Panel {
+            id: format!("panel{}", count),
+            label: format!("Panel {}", count),
+            rows: 1,
+            columns: 1,
+            ..Default::default()
+        }));
+        self.selected_panel = Some(self.drafts.len() - 1);
+    }
+
+    pub fn delete_panel(&mut self) {
+        let Some(index) = self.selected_panel else {
+            return;
+        };
+
+        let draft = self.drafts.remove(index);
+        if let Some(original_id) = draft.original_id {
+            self.removed_ids.push(original_id);
+        }
+
+        self.selected_panel = match self.drafts.is_empty() {
+            true => None,
+            false => Some(index.min(self.drafts.len() - 1)),
+        };
+    }
+
+    // negative moves up the list, positive down
+    pub fn move_panel(&mut self, offset: isize) {
+        let Some(index) = self.selected_panel else {
+            return;
+        };
+        let Some(target) = index
+            .checked_add_signed(offset)
+            .filter(|target| *target < self.drafts.len())
+        else {
+            return;
+        };
+
+        self.drafts.swap(index, target);
+        self.selected_panel = Some(target);
+    }
+
+    pub fn add_keypad(&mut self) {
+        let Some(keypad_ref) = self
+            .selected_keypad
+            .and_then(|index| self.keypad_refs.get(index))
+            .cloned()
+        else {
+            return;
+        };
+
+        // into the first free cell, or a new column on the end if it's full
+        self.edit(|panel| {
+            let taken = |position: GridPosition| {
+                panel
+                    .keypads
+                    .iter()
+                    .any(|keypad_ref| keypad_ref.grid_position() == position)
+            };
+            let free = (1..=panel.rows)
+                .flat_map(|row| {
+                    (1..=panel.columns).map(move |column| GridPosition::new(row, column))
+                })
+                .find(|position| !taken(*position));
+
+            let position = free.unwrap_or_else(|| {
+                panel.rows = panel.rows.max(1);
+                panel.columns += 1;
+                GridPosition::new(1, panel.columns)
+            });
+
+            panel.keypads.push(KeypadRef {
+                row: position.row,
+                column: position.column,
+                ..keypad_ref
+            });
+        });
+    }
+
+    // a half typed number is ignored rather than thrown into the panel
+    pub fn place_keypad(&mut self, index: usize, row: Option<usize>, column: Option<usize>) {
+        self.edit(|panel| {
+            if let Some(keypad_ref) = panel.keypads.get_mut(index) {
+                keypad_ref.row = row.unwrap_or(keypad_ref.row);
+                keypad_ref.column = column.unwrap_or(keypad_ref.column);
+            }
+        });
+    }
+
+    // what would stop the composer saving, if anything
+    pub fn problem(&self, ui: &UiModel) -> Option<String> {
+        if self.drafts.is_empty() {
+            return Some("There has to be at least one panel".to_string());
+        }
+
+        for (index, draft) in self.drafts.iter().enumerate() {
+            let panel = &draft.panel;
+
+            if !valid_id(&panel.id) {
+                return Some(format!(
+                    "Panel '{}' - the id can only use letters, digits, '_' and '-'",
+                    panel.label
+                ));
+            }
+            // panel_list.ron sits alongside the panels in panel_definitions
+            if panel.id == "panel_list" {
+                return Some("The id 'panel_list' is kept for the list of panels".to_string());
+            }
+            if self.drafts[..index]
+                .iter()
+                .any(|other| other.panel.id == panel.id)
+            {
+                return Some(format!("More than one panel has the id '{}'", panel.id));
+            }
+            if panel.label.trim().is_empty() {
+                return Some(format!("Panel '{}' needs a label", panel.id));
+            }
+            if panel.keypads.is_empty() {
+                return Some(format!("Panel '{}' needs at least one keypad", panel.label));
+            }
+            if let Some(error) = ui
+                .validate_panel(&PanelView::from(panel.clone()))
+                .into_iter()
+                .next()
+            {
+                return Some(format!("Panel '{}' - {}", panel.label, error.kind));
+            }
+        }
+
+        None
+    }
+
+    // old ids to clear out - deleted panels and the old name of renamed ones,
+    // unless some panel in the list is still using that id
+    pub fn stale_ids(&self) -> Vec<String> {
+        self.removed_ids
+            .iter()
+            .chain(self.drafts.iter().filter_map(|draft| {
+                draft
+                    .original_id
+                    .as_ref()
+                    .filter(|original_id| **original_id != draft.panel.id)
+            }))
+            .filter(|id| !self.drafts.iter().any(|draft| draft.panel.id == **id))
+            .cloned()
+            .collect()
+    }
+}
@@ -0,0 +1,33 @@
+// everything that can happen in the panel composer window
+
+use cosmic::iced::window;
+use std::path::PathBuf;
+
+use crate::data::DataError;
+
+#[derive(Debug, Clone)]
+pub enum PanelComposerMessage {
+    Opened(window::Id),
+
+    SelectPanel(usize),
+    NewPanel,
+    DeletePanel,
+    MovePanelUp,
+    MovePanelDown,
+
+    SetId(String),
+    SetLabel(String),
+    SetTooltip(String),
+    SetRows(String),
+    SetColumns(String),
+
+    SelectKeypad(usize),
+    AddKeypad,
+    RemoveKeypad(usize),
+    SetKeypadRow(usize, String),
+    SetKeypadColumn(usize, String),
+
+    Save,
+    Saved(Result<PathBuf, DataError>),
+    Close,
+}
@@ -0,0 +1,29 @@
+// one panel in the composer - the draft itself, the id it was loaded under
+// (so a rename can clear out the old file) and whether it needs writing
+
+use crate::data::Panel;
+
+#[derive(Debug, Default, Clone)]
+pub struct PanelDraft {
+    pub panel: Panel,
+    pub original_id: Option<String>,
+    pub changed: bool,
+}
+
+impl PanelDraft {
+    pub fn new(panel: Panel) -> Self {
+        Self {
+            original_id: None,
+            changed: true,
+            panel,
+        }
+    }
+
+    pub fn loaded(panel: Panel) -> Self {
+        Self {
+            original_id: Some(panel.id.clone()),
+            changed: false,
+            panel,
+        }
+    }
+}
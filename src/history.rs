@@ -0,0 +1,11 @@
+pub mod helper;
+mod model;
+
+pub(crate) use model::ExportFormat;
+pub(crate) use model::HistoryEntry;
+pub(crate) use model::HistoryTape;
+pub(crate) use model::NumberFormat;
+pub(crate) use model::SESSIONS_DIR;
+pub(crate) use model::Session;
+pub(crate) use model::SessionBrowser;
+pub(crate) use model::SessionBrowserMessage;
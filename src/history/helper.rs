@@ -0,0 +1,9 @@
+mod export_history;
+mod list_sessions;
+mod render_session_browser;
+mod save_session;
+mod session_browser_update;
+
+pub(crate) use export_history::export_history;
+pub(crate) use list_sessions::list_sessions;
+pub(crate) use save_session::save_session;
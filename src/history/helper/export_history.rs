@@ -0,0 +1,217 @@
+// write a history tape out as CSV, a Markdown table or JSON - one row per
+// calculation with the expression, the result, the labels of the keys that
+// were pressed and when it finished
+//
+// CSV and Markdown results are written the way the locale writes numbers,
+// so they paste straight into a report. JSON is read by programs, so it
+// gets the plain decimal and a list of labels whatever the locale. the
+// label lookup is handed in, the GUI has the keys loaded already and the
+// command line loads them as it goes
+
+use serde_json::json;
+
+use crate::history::ExportFormat;
+use crate::history::HistoryEntry;
+use crate::history::HistoryTape;
+use crate::history::NumberFormat;
+use crate::ui::KeyId;
+
+const HEADINGS: [&str; 4] = ["Expression", "Result", "Keys", "Timestamp"];
+
+pub fn export_history(
+    tape: &HistoryTape,
+    format: ExportFormat,
+    numbers: &NumberFormat,
+    label: impl Fn(&KeyId) -> String,
+) -> String {
+    let rows = || -> Vec<[String; 4]> {
+        tape.into_iter()
+            .map(|entry| row(entry, numbers, &label))
+            .collect()
+    };
+
+    match format {
+        ExportFormat::Csv => csv(&rows()),
+        ExportFormat::Markdown => markdown(&rows()),
+        ExportFormat::Json => json(tape, &label),
+    }
+}
+
+fn row(
+    entry: &HistoryEntry,
+    numbers: &NumberFormat,
+    label: &impl Fn(&KeyId) -> String,
+) -> [String; 4] {
+    [
+        entry.expression.clone(),
+        numbers.format(entry.result),
+        entry.keys.iter().map(label).collect::<Vec<_>>().join(" "),
+        entry.timestamp.to_rfc3339(),
+    ]
+}
+
+// every field is quoted - a locale with a decimal comma would split the
+// results otherwise
+fn csv(rows: &[[String; 4]]) -> String {
+    let line = |fields: &[String]| {
+        fields
+            .iter()
+            .map(|field| format!("\"{}\"", field.replace('"', "\"\"")))
+            .collect::<Vec<_>>()
+            .join(",")
+    };
+
+    let mut lines = vec![line(&HEADINGS.map(str::to_string))];
+    lines.extend(rows.iter().map(|row| line(row)));
+    lines.join("\n") + "\n"
+}
+
+fn markdown(rows: &[[String; 4]]) -> String {
+    let line = |fields: &[String]| {
+        let cells: Vec<String> = fields
+            .iter()
+            .map(|field| field.replace('|', "\\|").replace('\n', " "))
+            .collect();
+        format!("| {} |", cells.join(" | "))
+    };
+
+    let mut lines = vec![
+        line(&HEADINGS.map(str::to_string)),
+        "| --- | ---: | --- | --- |".to_string(),
+    ];
+    lines.extend(rows.iter().map(|row| line(row)));
+    lines.join("\n") + "\n"
+}
+
+fn json(tape: &HistoryTape, label: &impl Fn(&KeyId) -> String) -> String {
+    let entries: Vec<serde_json::Value> = tape
+        .into_iter()
+        .map(|entry| {
+            json!({
+                "expression": entry.expression,
+                "result": entry.result.normalize().to_string(),
+                "keys": entry.keys.iter().map(label).collect::<Vec<_>>(),
+                "timestamp": entry.timestamp.to_rfc3339(),
+            })
+        })
+        .collect();
+
+    serde_json::to_string_pretty(&entries).unwrap_or_default() + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calc::Calculation;
+    use num_format::Grouping;
+    use rust_decimal::Decimal;
+
+    // one calculation, 1234.5 * 2, pressed on two keys
+    fn tape(expression: &str) -> HistoryTape {
+        let mut tape = HistoryTape::default();
+        tape.press(KeyId::new("test", "times"));
+        tape.press(KeyId::new("test", "equals"));
+        tape.record(Calculation {
+            start: Decimal::new(12345, 1),
+            expression: expression.to_string(),
+            result: Decimal::new(24690, 1),
+        });
+        tape
+    }
+
+    fn label(key_id: &KeyId) -> String {
+        match key_id.id.as_str() {
+            "times" => "×".to_string(),
+            _ => "=".to_string(),
+        }
+    }
+
+    fn timestamp(tape: &HistoryTape) -> String {
+        tape.get(0).unwrap().timestamp.to_rfc3339()
+    }
+
+    fn decimal_comma() -> NumberFormat {
+        NumberFormat {
+            decimal: ",".to_string(),
+            separator: ".".to_string(),
+            grouping: Grouping::Standard,
+        }
+    }
+
+    #[test]
+    fn csv_quotes_every_field() {
+        let cases = [
+            (
+                "1234.5 * 2",
+                NumberFormat::default(),
+                r#""1234.5 * 2","2,469","× =""#,
+            ),
+            (
+                r#"say "hi", twice"#,
+                NumberFormat::default(),
+                r#""say ""hi"", twice","2,469","× =""#,
+            ),
+            (
+                "1234,5 * 2",
+                decimal_comma(),
+                r#""1234,5 * 2","2.469","× =""#,
+            ),
+        ];
+
+        for (expression, numbers, expected) in cases {
+            let tape = tape(expression);
+            let output = export_history(&tape, ExportFormat::Csv, &numbers, label);
+            let expected = format!(
+                "\"Expression\",\"Result\",\"Keys\",\"Timestamp\"\n{},\"{}\"\n",
+                expected,
+                timestamp(&tape)
+            );
+            assert_eq!(output, expected, "{}", expression);
+        }
+    }
+
+    #[test]
+    fn markdown_escapes_pipes() {
+        let cases = [
+            ("1234.5 * 2", "1234.5 * 2"),
+            ("|1234.5| * 2", "\\|1234.5\\| * 2"),
+            ("1234.5\n* 2", "1234.5 * 2"),
+        ];
+
+        for (expression, expected) in cases {
+            let tape = tape(expression);
+            let output = export_history(
+                &tape,
+                ExportFormat::Markdown,
+                &NumberFormat::default(),
+                label,
+            );
+            let expected = format!(
+                "| Expression | Result | Keys | Timestamp |\n\
+                 | --- | ---: | --- | --- |\n\
+                 | {} | 2,469 | × = | {} |\n",
+                expected,
+                timestamp(&tape)
+            );
+            assert_eq!(output, expected, "{:?}", expression);
+        }
+    }
+
+    #[test]
+    fn json_is_locale_free() {
+        for numbers in [NumberFormat::default(), decimal_comma()] {
+            let tape = tape("1234.5 * 2");
+            let output = export_history(&tape, ExportFormat::Json, &numbers, label);
+            let value: serde_json::Value = serde_json::from_str(&output).unwrap();
+            assert_eq!(
+                value,
+                json!([{
+                    "expression": "1234.5 * 2",
+                    "result": "2469",
+                    "keys": ["×", "="],
+                    "timestamp": timestamp(&tape),
+                }])
+            );
+        }
+    }
+}
@@ -0,0 +1,25 @@
+// the names of the saved sessions, in alphabetical order
+
+use std::fs;
+
+use crate::data::DataRoot;
+use crate::history::SESSIONS_DIR;
+
+pub fn list_sessions() -> Vec<String> {
+    let Some(root) = DataRoot::user() else {
+        return Vec::new();
+    };
+    let Ok(entries) = fs::read_dir(root.path.join(SESSIONS_DIR)) else {
+        return Vec::new();
+    };
+
+    let mut names: Vec<String> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|extension| extension == "ron"))
+        .filter_map(|path| Some(path.file_stem()?.to_str()?.to_string()))
+        .collect();
+
+    names.sort();
+    names
+}
@@ -0,0 +1,112 @@
+// render the session browser window - the saved sessions down the left,
+// the name field and what can be done with the picked session on the right,
+// and the export controls along the bottom
+
+use cosmic::Element;
+use cosmic::iced::Length;
+use cosmic::widget::{button, column, dropdown, row, scrollable, text, text_input};
+
+use crate::app::Message;
+use crate::history::ExportFormat;
+use crate::history::SessionBrowser;
+use crate::history::SessionBrowserMessage;
+
+impl SessionBrowser {
+    pub fn view(&self, session: Option<&String>) -> Element<'_, Message> {
+        let browse = Message::SessionBrowser;
+
+        let mut sessions = column::with_capacity(self.names.len() + 1)
+            .spacing(5)
+            .width(Length::Fixed(220.0))
+            .push(text::heading("Sessions"));
+
+        if self.names.is_empty() {
+            sessions = sessions.push(text::caption("No saved sessions yet"));
+        }
+
+        for (index, name) in self.names.iter().enumerate() {
+            // the open session is marked
+            let label = match session == Some(name) {
+                true => format!("{} (open)", name),
+                false => name.clone(),
+            };
+            let entry = match self.selected == Some(index) {
+                true => button::suggested(label),
+                false => button::standard(label),
+            };
+            sessions = sessions.push(
+                entry
+                    .width(Length::Fill)
+                    .on_press(browse(SessionBrowserMessage::Select(index))),
+            );
+        }
+
+        let actions = column::with_capacity(3)
+            .spacing(10)
+            .push(
+                text_input("session name", &self.name)
+                    .on_input(move |name| browse(SessionBrowserMessage::SetName(name))),
+            )
+            .push(
+                button::suggested("Save the tape as this name")
+                    .on_press(browse(SessionBrowserMessage::SaveAs)),
+            )
+            .push(
+                row::with_capacity(4)
+                    .spacing(10)
+                    .push(button::standard("Open").on_press(browse(SessionBrowserMessage::Open)))
+                    .push(
+                        button::standard("Rename").on_press(browse(SessionBrowserMessage::Rename)),
+                    )
+                    .push(
+                        button::standard("Duplicate")
+                            .on_press(browse(SessionBrowserMessage::Duplicate)),
+                    )
+                    .push(
+                        button::destructive("Delete")
+                            .on_press(browse(SessionBrowserMessage::Delete)),
+                    ),
+            );
+
+        let selected_format = ExportFormat::ALL
+            .iter()
+            .position(|format| *format == self.format);
+        let export = row::with_capacity(5)
+            .spacing(10)
+            .push(text("Export as"))
+            .push(dropdown(
+                &ExportFormat::NAMES,
+                selected_format,
+                move |index| browse(SessionBrowserMessage::SelectFormat(index)),
+            ))
+            .push(button::standard("Copy tape").on_press(browse(SessionBrowserMessage::CopyTape)))
+            .push(
+                button::standard("Export tape").on_press(browse(SessionBrowserMessage::ExportTape)),
+            )
+            .push(
+                button::standard("Export picked session")
+                    .on_press(browse(SessionBrowserMessage::ExportSession)),
+            );
+
+        let mut content = column::with_capacity(5)
+            .spacing(15)
+            .padding(20)
+            .push(text::title4("Sessions"))
+            .push(
+                row::with_capacity(2)
+                    .spacing(30)
+                    .push(sessions)
+                    .push(actions),
+            )
+            .push(export);
+
+        if let Some(status) = &self.status {
+            content = content.push(text(status.clone()));
+        }
+
+        content =
+            content.push(button::standard("Close").on_press(browse(SessionBrowserMessage::Close)));
+
+        scrollable(content).into()
+    }
+}
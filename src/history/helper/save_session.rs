@@ -0,0 +1,45 @@
+// write the history tape to the open session - runs after every finished
+// calculation so the session is always up to date on disk
+//
+// the writes run one at a time, and each save is numbered when it's asked
+// for. a save that's overtaken by a newer one of the same session is
+// dropped, so an older tape can never land on top of a newer one
+
+use cosmic::app::Task;
+use std::collections::HashMap;
+use std::sync::LazyLock;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::app::Message;
+use crate::data::helper::save_ron;
+use crate::history::HistoryTape;
+use crate::history::Session;
+
+static REQUESTED: AtomicU64 = AtomicU64::new(0);
+static WRITTEN: LazyLock<Mutex<HashMap<String, u64>>> = LazyLock::new(Default::default);
+
+pub fn save_session(name: &str, tape: &HistoryTape) -> Task<Message> {
+    let session = Session::new(name, tape.clone());
+    let number = REQUESTED.fetch_add(1, Ordering::Relaxed) + 1;
+
+    Task::future(async move {
+        let path = Session::ron_path(&session.name);
+        let mut written = WRITTEN
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        let overtaken = written
+            .get(&session.name)
+            .is_some_and(|newest| *newest > number);
+        let result = match overtaken {
+            true => Ok(path),
+            false => save_ron(&path, &session),
+        };
+        if !overtaken && result.is_ok() {
+            written.insert(session.name.clone(), number);
+        }
+
+        cosmic::action::app(Message::SessionSaved(result))
+    })
+}
@@ -0,0 +1,219 @@
+// handler for the session browser messages - the files are small so they
+// are read and written straight away, anything that changes which session
+// is open goes back to the app to deal with
+//
+// exports go into the documents folder, or onto the clipboard for the tape
+
+use chrono::Local;
+use cosmic::app::Task;
+use cosmic::iced::{clipboard, window};
+use std::fs;
+
+use crate::app::Message;
+use crate::data::DataError;
+use crate::data::helper::remove_ron;
+use crate::data::helper::save_ron;
+use crate::editor::helper::valid_id;
+use crate::history::ExportFormat;
+use crate::history::HistoryTape;
+use crate::history::NumberFormat;
+use crate::history::Session;
+use crate::history::SessionBrowser;
+use crate::history::SessionBrowserMessage;
+use crate::history::helper::export_history;
+use crate::ui::Keys;
+
+impl SessionBrowser {
+    pub fn update(
+        &mut self,
+        message: SessionBrowserMessage,
+        tape: &HistoryTape,
+        session: Option<&String>,
+        keys: &Keys,
+    ) -> Task<Message> {
+        use SessionBrowserMessage::*;
+
+        let result = match message {
+            Opened(_) => return Task::none(),
+
+            Select(index) => {
+                self.selected = Some(index);
+                if let Some(name) = self.selected_name() {
+                    self.name = name.clone();
+                }
+                return Task::none();
+            }
+            SetName(name) => {
+                self.name = name;
+                return Task::none();
+            }
+
+            SaveAs => self.save_as(tape),
+            Open => self.open(),
+            Rename => self.rename(session),
+            Duplicate => self.duplicate(),
+            Delete => self.delete(session),
+
+            SelectFormat(index) => {
+                self.format = ExportFormat::ALL.get(index).copied().unwrap_or_default();
+                return Task::none();
+            }
+            CopyTape => {
+                self.status = Some(format!("Copied the tape as {}", self.format.as_str()));
+                return clipboard::write(self.export(tape, keys));
+            }
+            ExportTape => {
+                let name = match session {
+                    Some(session) => session.clone(),
+                    None => Local::now().format("history-%Y%m%d-%H%M%S").to_string(),
+                };
+                let contents = self.export(tape, keys);
+                self.write_export(&name, &contents)
+            }
+            ExportSession => self.picked().and_then(|name| {
+                let session = Session::from_ron(&name).map_err(failed)?;
+                let contents = self.export(&session.tape, keys);
+                self.write_export(&name, &contents)
+            }),
+
+            Close => {
+                return match self.window_id.take() {
+                    Some(window_id) => window::close(window_id),
+                    None => Task::none(),
+                };
+            }
+        };
+
+        match result {
+            Ok((status, message)) => {
+                self.status = Some(status);
+                match message {
+                    Some(message) => Task::done(cosmic::action::app(message)),
+                    None => Task::none(),
+                }
+            }
+            Err(error) => {
+                self.status = Some(error);
+                Task::none()
+            }
+        }
+    }
+
+    fn export(&self, tape: &HistoryTape, keys: &Keys) -> String {
+        export_history(
+            tape,
+            self.format,
+            &NumberFormat::system(),
+            |key_id| match keys.get(key_id) {
+                Some(key) => key.label.clone(),
+                None => key_id.to_string(),
+            },
+        )
+    }
+
+    fn write_export(
+        &self,
+        name: &str,
+        contents: &str,
+    ) -> Result<(String, Option<Message>), String> {
+        let Some(dir) = dirs::document_dir().or_else(dirs::home_dir) else {
+            return Err("There is no documents folder to export to".to_string());
+        };
+        let path = dir.join(format!("{}.{}", name, self.format.extension()));
+
+        fs::write(&path, contents).map_err(|e| format!("Failed: {}", e))?;
+        Ok((format!("Exported {}", path.display()), None))
+    }
+
+    // the new name has to make a file name, like any other id
+    fn new_name(&self) -> Result<String, String> {
+        let name = self.name.trim();
+        match valid_id(name) {
+            true => Ok(name.to_string()),
+            false => Err("A session name can only use letters, digits, '_' and '-'".to_string()),
+        }
+    }
+
+    fn picked(&self) -> Result<String, String> {
+        self.selected_name()
+            .cloned()
+            .ok_or_else(|| "Pick a session first".to_string())
+    }
+
+    fn save_as(&mut self, tape: &HistoryTape) -> Result<(String, Option<Message>), String> {
+        let name = self.new_name()?;
+        if self.names.contains(&name) {
+            return Err(format!("There is already a session called {}", name));
+        }
+        let path = save_ron(
+            &Session::ron_path(&name),
+            &Session::new(&name, tape.clone()),
+        )
+        .map_err(failed)?;
+
+        self.refresh(Some(&name));
+        Ok((
+            format!("Saved {}", path.display()),
+            Some(Message::SessionChanged(Some(name))),
+        ))
+    }
+
+    fn open(&mut self) -> Result<(String, Option<Message>), String> {
+        let session = Session::from_ron(&self.picked()?).map_err(failed)?;
+
+        Ok((
+            format!("Opened {}", session.name),
+            Some(Message::SessionOpened(session)),
+        ))
+    }
+
+    // a rename of the open session keeps it open under the new name
+    fn rename(&mut self, session: Option<&String>) -> Result<(String, Option<Message>), String> {
+        let old_name = self.picked()?;
+        let name = self.new_name()?;
+        if name != old_name && self.names.contains(&name) {
+            return Err(format!("There is already a session called {}", name));
+        }
+
+        let mut renamed = Session::from_ron(&old_name).map_err(failed)?;
+        renamed.name = name.clone();
+        save_ron(&Session::ron_path(&name), &renamed).map_err(failed)?;
+        if name != old_name {
+            remove_ron(&Session::ron_path(&old_name)).map_err(failed)?;
+        }
+
+        self.refresh(Some(&name));
+        let message =
+            (session == Some(&old_name)).then(|| Message::SessionChanged(Some(name.clone())));
+        Ok((format!("Renamed {} to {}", old_name, name), message))
+    }
+
+    fn duplicate(&mut self) -> Result<(String, Option<Message>), String> {
+        let old_name = self.picked()?;
+        let name = self.new_name()?;
+        if self.names.contains(&name) {
+            return Err(format!("There is already a session called {}", name));
+        }
+
+        let mut copy = Session::from_ron(&old_name).map_err(failed)?;
+        copy.name = name.clone();
+        save_ron(&Session::ron_path(&name), &copy).map_err(failed)?;
+
+        self.refresh(Some(&name));
+        Ok((format!("Copied {} to {}", old_name, name), None))
+    }
+
+    // deleting the open session leaves the tape as it is, just not saved
+    fn delete(&mut self, session: Option<&String>) -> Result<(String, Option<Message>), String> {
+        let name = self.picked()?;
+        remove_ron(&Session::ron_path(&name)).map_err(failed)?;
+
+        self.refresh(None);
+        let message = (session == Some(&name)).then_some(Message::SessionChanged(None));
+        Ok((format!("Deleted {}", name), message))
+    }
+}
+
+fn failed(error: DataError) -> String {
+    format!("Failed: {}", error)
+}
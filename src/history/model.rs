@@ -0,0 +1,16 @@
+mod export_format;
+mod history_entry;
+mod history_tape;
+mod number_format;
+mod session;
+mod session_browser;
+mod session_browser_message;
+
+pub use export_format::ExportFormat;
+pub use history_entry::HistoryEntry;
+pub use history_tape::HistoryTape;
+pub use number_format::NumberFormat;
+pub use session::SESSIONS_DIR;
+pub use session::Session;
+pub use session_browser::SessionBrowser;
+pub use session_browser_message::SessionBrowserMessage;
@@ -0,0 +1,42 @@
+// the formats a history tape can be exported as
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    #[default]
+    Csv,
+    Markdown,
+    Json,
+}
+
+impl ExportFormat {
+    pub const ALL: [ExportFormat; 3] = [Self::Csv, Self::Markdown, Self::Json];
+    pub const NAMES: [&'static str; 3] = [
+        Self::Csv.as_str(),
+        Self::Markdown.as_str(),
+        Self::Json.as_str(),
+    ];
+
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Csv => "CSV",
+            Self::Markdown => "Markdown",
+            Self::Json => "JSON",
+        }
+    }
+
+    pub const fn extension(&self) -> &'static str {
+        match self {
+            Self::Csv => "csv",
+            Self::Markdown => "md",
+            Self::Json => "json",
+        }
+    }
+
+    // the command line names - the extension or the full name
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|format| {
+            name.eq_ignore_ascii_case(format.extension())
+                || name.eq_ignore_ascii_case(format.as_str())
+        })
+    }
+}
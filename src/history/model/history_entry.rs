@@ -0,0 +1,30 @@
+// one line on the history tape - a calculation along with the keys that
+// were pressed to get it and when it finished
+
+use chrono::{DateTime, Local};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::calc::Calculation;
+use crate::ui::KeyId;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct HistoryEntry {
+    pub start: Decimal,
+    pub keys: Vec<KeyId>,
+    pub expression: String,
+    pub result: Decimal,
+    pub timestamp: DateTime<Local>,
+}
+
+impl HistoryEntry {
+    pub fn new(calculation: Calculation, keys: Vec<KeyId>) -> Self {
+        Self {
+            start: calculation.start,
+            keys,
+            expression: calculation.expression,
+            result: calculation.result,
+            timestamp: Local::now(),
+        }
+    }
+}
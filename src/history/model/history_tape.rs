@@ -0,0 +1,59 @@
+// the history tape - every finished calculation, oldest first
+//
+// the keys pressed are collected as they go, and handed to the entry when
+// the calculation finishes. a clear or an error throws them away
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::calc::Calculation;
+use crate::history::HistoryEntry;
+use crate::ui::KeyId;
+
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+pub struct HistoryTape {
+    entries: Vec<HistoryEntry>,
+    #[serde(skip)]
+    pressed: Vec<KeyId>,
+}
+
+impl HistoryTape {
+    pub fn press(&mut self, key_id: KeyId) {
+        self.pressed.push(key_id);
+    }
+
+    pub fn record(&mut self, calculation: Calculation) {
+        let keys = std::mem::take(&mut self.pressed);
+        self.entries.push(HistoryEntry::new(calculation, keys));
+    }
+
+    pub fn abandon(&mut self) {
+        self.pressed.clear();
+    }
+
+    pub fn get(&self, index: usize) -> Option<&HistoryEntry> {
+        self.entries.get(index)
+    }
+
+    // every result on the tape, oldest first - what {Ans} reaches back into
+    pub fn results(&self) -> Vec<Decimal> {
+        self.entries.iter().map(|entry| entry.result).collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl<'a> IntoIterator for &'a HistoryTape {
+    type Item = &'a HistoryEntry;
+    type IntoIter = std::slice::Iter<'a, HistoryEntry>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.iter()
+    }
+}
@@ -0,0 +1,123 @@
+// how numbers are written for people to read - the decimal point, the
+// thousands separator and how the digits are grouped, all taken from the
+// system locale (LC_NUMERIC and friends) with plain 1,234.5 as the fallback
+
+use num_format::{Grouping, SystemLocale};
+use rust_decimal::Decimal;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NumberFormat {
+    pub decimal: String,
+    pub separator: String,
+    pub grouping: Grouping,
+}
+
+impl NumberFormat {
+    pub fn system() -> Self {
+        match SystemLocale::default() {
+            Ok(locale) => Self {
+                decimal: locale.decimal().to_string(),
+                separator: locale.separator().to_string(),
+                grouping: locale.grouping(),
+            },
+            Err(_) => Self::default(),
+        }
+    }
+
+    pub fn format(&self, value: Decimal) -> String {
+        let plain = value.normalize().to_string();
+        let (sign, digits) = match plain.strip_prefix('-') {
+            Some(digits) => ("-", digits),
+            None => ("", plain.as_str()),
+        };
+        let (whole, fraction) = match digits.split_once('.') {
+            Some((whole, fraction)) => (whole, Some(fraction)),
+            None => (digits, None),
+        };
+
+        let mut formatted = format!("{}{}", sign, self.group(whole));
+        if let Some(fraction) = fraction {
+            formatted.push_str(&self.decimal);
+            formatted.push_str(fraction);
+        }
+        formatted
+    }
+
+    // standard is groups of 3, indian is 3 then groups of 2
+    fn group(&self, whole: &str) -> String {
+        let group_sizes = match self.grouping {
+            Grouping::Standard => (3, 3),
+            Grouping::Indian => (3, 2),
+            Grouping::Posix => return whole.to_string(),
+        };
+
+        let mut groups: Vec<&str> = Vec::new();
+        let mut end = whole.len();
+        let mut size = group_sizes.0;
+        while end > size {
+            groups.push(&whole[end - size..end]);
+            end -= size;
+            size = group_sizes.1;
+        }
+        groups.push(&whole[..end]);
+
+        groups.reverse();
+        groups.join(&self.separator)
+    }
+}
+
+impl Default for NumberFormat {
+    fn default() -> Self {
+        Self {
+            decimal: ".".to_string(),
+            separator: ",".to_string(),
+            grouping: Grouping::Standard,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grouping() {
+        let format = |grouping, decimal: &str, separator: &str| NumberFormat {
+            decimal: decimal.to_string(),
+            separator: separator.to_string(),
+            grouping,
+        };
+        let standard = format(Grouping::Standard, ".", ",");
+        let indian = format(Grouping::Indian, ".", ",");
+        let posix = format(Grouping::Posix, ".", ",");
+        let european = format(Grouping::Standard, ",", ".");
+
+        let cases = [
+            (&standard, "0", "0"),
+            (&standard, "999", "999"),
+            (&standard, "1000", "1,000"),
+            (&standard, "1234567.5", "1,234,567.5"),
+            (&standard, "-1234.50", "-1,234.5"),
+            (&indian, "999", "999"),
+            (&indian, "1000", "1,000"),
+            (&indian, "100000", "1,00,000"),
+            (&indian, "12345678.25", "1,23,45,678.25"),
+            (&indian, "-1234567", "-12,34,567"),
+            (&posix, "1234567.5", "1234567.5"),
+            (&posix, "-1000", "-1000"),
+            (&european, "1234567.5", "1.234.567,5"),
+            (&european, "0.25", "0,25"),
+        ];
+
+        for (numbers, value, expected) in cases {
+            let value: Decimal = value.parse().unwrap();
+            assert_eq!(
+                numbers.format(value),
+                expected,
+                "{:?} {}",
+                numbers.grouping,
+                value
+            );
+        }
+    }
+}
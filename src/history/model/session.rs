@@ -0,0 +1,54 @@
+// a named session - a history tape saved as sessions/<name>.ron in the
+// user data root, where it can be picked up again later
+
+use serde::{Deserialize, Serialize};
+use std::io;
+use std::path::PathBuf;
+
+use crate::data::DataError;
+use crate::data::DataOrigin;
+use crate::data::DataRoot;
+use crate::data::DataSource;
+use crate::data::helper::load_and_parse;
+use crate::history::HistoryTape;
+
+pub const SESSIONS_DIR: &str = "sessions";
+
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+pub struct Session {
+    pub name: String,
+    pub tape: HistoryTape,
+}
+
+impl Session {
+    pub fn new(name: impl Into<String>, tape: HistoryTape) -> Self {
+        Self {
+            name: name.into(),
+            tape,
+        }
+    }
+
+    // relative to the user data root
+    pub fn ron_path(name: &str) -> PathBuf {
+        PathBuf::from(SESSIONS_DIR).join(format!("{}.ron", name))
+    }
+
+    // the file name is the session name, whatever the file says inside
+    pub fn from_ron(name: &str) -> Result<Self, DataError> {
+        let relative_path = Self::ron_path(name);
+        let Some(root) = DataRoot::user() else {
+            return Err(
+                DataError::from(io::Error::from(io::ErrorKind::NotFound)).with_path(relative_path)
+            );
+        };
+
+        let source = DataSource {
+            origin: DataOrigin::User,
+            path: root.path.join(relative_path),
+        };
+        let mut session = load_and_parse::<Self>(&source).map_err(|e| e.with_id(name))?;
+
+        session.name = name.to_string();
+        Ok(session)
+    }
+}
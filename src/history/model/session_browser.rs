@@ -0,0 +1,44 @@
+// the session browser - the saved sessions, which one is picked, the
+// name to save, rename or duplicate it as and the format to export in
+
+use cosmic::iced::window;
+
+use crate::history::ExportFormat;
+use crate::history::helper::list_sessions;
+
+#[derive(Debug, Default, Clone)]
+pub struct SessionBrowser {
+    pub window_id: Option<window::Id>,
+
+    pub names: Vec<String>,
+    pub selected: Option<usize>,
+    pub name: String,
+    pub format: ExportFormat,
+
+    pub status: Option<String>,
+}
+
+impl SessionBrowser {
+    // start over - with the open session picked, if there is one
+    pub fn start(&mut self, session: Option<&String>) {
+        let window_id = self.window_id;
+        *self = Self::default();
+        self.window_id = window_id;
+
+        self.refresh(session.map(String::as_str));
+        if let Some(session) = session {
+            self.name = session.clone();
+        }
+    }
+
+    // re-read the sessions on disk, keeping the pick on the named one
+    pub fn refresh(&mut self, selected: Option<&str>) {
+        self.names = list_sessions();
+        self.selected =
+            selected.and_then(|name| self.names.iter().position(|candidate| candidate == name));
+    }
+
+    pub fn selected_name(&self) -> Option<&String> {
+        self.names.get(self.selected?)
+    }
+}
@@ -0,0 +1,24 @@
+// everything that can happen in the session browser window
+
+use cosmic::iced::window;
+
+#[derive(Debug, Clone)]
+pub enum SessionBrowserMessage {
+    Opened(window::Id),
+
+    Select(usize),
+    SetName(String),
+
+    SaveAs,
+    Open,
+    Rename,
+    Duplicate,
+    Delete,
+
+    SelectFormat(usize),
+    CopyTape,
+    ExportTape,
+    ExportSession,
+
+    Close,
+}
@@ -1,12 +1,22 @@
 // SPDX-License-Identifier: MPL-2.0
 
 pub mod app;
+pub mod calc;
+pub mod cli;
 pub mod config;
 pub mod data;
+pub mod editor;
+pub mod history;
 pub mod i18n;
 pub mod ui;
 
 fn main() -> cosmic::iced::Result {
+    // headless commands never open a window
+    let command = cli::CliCommand::from_args(std::env::args().skip(1));
+    if !matches!(command, Ok(cli::CliCommand::Gui)) {
+        std::process::exit(cli::helper::run_cli(command));
+    }
+
     // Get the system's preferred languages.
     let requested_languages = i18n_embed::DesktopLanguageRequester::requested_languages();
 
@@ -12,6 +12,7 @@ pub(crate) use model::KeySvg;
 pub(crate) use model::KeypadId;
 pub(crate) use model::KeypadView;
 pub(crate) use model::Keypads;
+pub(crate) use model::Keys;
 
 pub(crate) use model::PanelView;
 pub(crate) use model::Panels;
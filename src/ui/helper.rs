@@ -1,10 +1,21 @@
 mod build_button_grid;
+mod cached_library_loaded;
+mod key_loaded;
+mod keygrids_loaded;
 mod keypad_loaded;
 mod keypads_loaded;
+mod library_changed;
+mod library_reloaded;
 mod panel_list_loaded;
 mod panel_loaded;
 mod panels_loaded;
+mod render_display;
+mod render_error;
+mod render_history_tape;
 mod render_keypad_view;
 mod render_panel_view;
+mod render_settings;
+mod validate_library;
 
 pub(crate) use build_button_grid::build_button_grid;
+pub(crate) use build_button_grid::keypad_size;
@@ -1,71 +1,162 @@
+// build the button grid for a keypad
+//
+// every cell is KEY_WIDTH x KEY_HEIGHT with KEY_SPACING between them. a key
+// can span more than one cell, so rather than rows of buttons the keys are
+// stacked over a space the size of the whole grid, each one pushed into
+// place from the top left and sized to cover its cells and the spacing
+// between them. cells under a spanning key, spacers and cells with no key
+// at all are simply left showing the space underneath, separators get a
+// line drawn through them
+//
+// the whole keypad is a fixed size too, see keypad_size, so a panel can line
+// its keypads up in columns and rows before any of them are laid out
+
 use cosmic::Element;
-use cosmic::iced::{Alignment, Length};
-use cosmic::widget::{button, column, container, row, text, tooltip};
+use cosmic::iced::widget::Stack;
+use cosmic::iced::{Alignment, Length, Padding};
+use cosmic::widget::{Space, button, column, container, divider, text, tooltip};
 
 use crate::app::Message;
 use crate::ui::GridPosition;
-use crate::ui::KeyGrid;
+use crate::ui::GridSpan;
+use crate::ui::KeyGridId;
+use crate::ui::KeyGrids;
+use crate::ui::KeypadView;
 use crate::ui::style::keypad_container_style;
 
+const KEY_WIDTH: f32 = 70.0;
+const KEY_HEIGHT: f32 = 40.0;
+const KEY_SPACING: f32 = 10.0;
+const HEADING_HEIGHT: f32 = 20.0;
+const HEADING_SPACING: f32 = 10.0;
+const KEYPAD_PADDING: f32 = 15.0;
+
 pub fn build_button_grid(
-    keygrid: &KeyGrid,
-    _rows: usize,
-    _columns: usize,
+    keygrids: &KeyGrids,
+    keypad_view: &KeypadView,
 ) -> Element<'static, Message> {
-    let mut rows: Vec<Element<'static, Message>> = Vec::with_capacity(_rows as usize);
-    rows.push(text("Keypad").into());
-
-    for iter_row in 1..=_rows {
-        let mut buttons: Vec<Element<'static, Message>> = Vec::with_capacity(_columns as usize);
-
-        for iter_column in 1..=_columns {
-            // get the Key metadata
-            let grid_index = GridPosition {
-                row: iter_row,
-                column: iter_column,
-            };
-            let key = keygrid.get(&grid_index);
-
-            let label_text = text(key.label.clone())
-                .width(Length::Fill)
-                .height(Length::Fill)
-                .align_x(Alignment::Center)
-                .align_y(Alignment::Center);
-
-            let key_button = button::custom(label_text)
-                .width(70.0)
-                .height(40.0)
-                .on_press(Message::KeyPressed(key.id.clone()));
-
-            match key.tooltip_text.as_str() {
-                "" => {
-                    buttons.push(key_button.into());
-                }
-                _ => {
-                    let key_tooltip = text(key.tooltip_text.clone());
-                    let key_with_tooltip = tooltip(key_button, key_tooltip, tooltip::Position::Top)
-                        .gap(10)
-                        .into();
-
-                    buttons.push(key_with_tooltip);
-                }
-            }
-        }
-
-        rows.push(
-            row::with_children(buttons)
-                .spacing(10)
-                .width(Length::Shrink)
+    let keypad_id = &keypad_view.id;
+    let (width, height) = spanned_size(GridSpan::new(keypad_view.rows, keypad_view.columns));
+
+    // the bottom layer sets the size of the whole grid
+    let mut layers: Vec<Element<'static, Message>> = Vec::with_capacity(keypad_view.keys.len() + 1);
+    layers.push(Space::new(width, height).into());
+
+    for key_ref in &keypad_view.keys {
+        // get the Key metadata
+        let keygrid_id = KeyGridId {
+            keypad_id: keypad_id.clone(),
+            grid_position: key_ref.grid_position,
+        };
+        let Some(key) = keygrids.get(&keygrid_id) else {
+            continue;
+        };
+
+        let label_text = text(key.label.clone())
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .align_x(Alignment::Center)
+            .align_y(Alignment::Center);
+
+        let (key_width, key_height) = spanned_size(key_ref.grid_span);
+        let key_button = button::custom(label_text)
+            .width(key_width)
+            .height(key_height)
+            .on_press(Message::KeyPressed(key_ref.key_id.clone()));
+
+        // every key tooltip ends with where the key definition came from
+        let tooltip_text = match key.tooltip_text.as_str() {
+            "" => key.source().to_string(),
+            _ => format!("{}\n{}", key.tooltip_text, key.source()),
+        };
+        let key_with_tooltip =
+            tooltip(key_button, text(tooltip_text), tooltip::Position::Top).gap(10);
+
+        layers.push(
+            container(key_with_tooltip)
+                .padding(cell_offset(key_ref.grid_position))
                 .into(),
         );
     }
-    let keypad = column::with_children(rows)
-        .spacing(10)
+
+    // a spacer is just the empty space, a separator draws a line through it
+    for spacer_ref in keypad_view
+        .spacers
+        .iter()
+        .filter(|spacer_ref| spacer_ref.is_separator())
+    {
+        let span = spacer_ref.grid_span();
+        let (separator_width, separator_height) = spanned_size(span);
+
+        let line: Element<'static, Message> = match span.column_span >= span.row_span {
+            true => divider::horizontal::default().into(),
+            false => divider::vertical::default().into(),
+        };
+        let separator = container(line)
+            .width(separator_width)
+            .height(separator_height)
+            .align_x(Alignment::Center)
+            .align_y(Alignment::Center);
+
+        layers.push(
+            container(separator)
+                .padding(cell_offset(spacer_ref.grid_position()))
+                .into(),
+        );
+    }
+
+    // the keypad heading says which data root the keypad came from
+    let heading = tooltip(
+        text(keypad_view.label.clone()).height(HEADING_HEIGHT),
+        text(keypad_view.source.to_string()),
+        tooltip::Position::Top,
+    );
+
+    let keypad = column::with_capacity(2)
+        .push(heading)
+        .push(Stack::with_children(layers))
+        .spacing(HEADING_SPACING)
         .width(Length::Shrink)
         .height(Length::Shrink);
 
+    let (keypad_width, keypad_height) = keypad_size(keypad_view);
     container(keypad)
-        .padding(15)
+        .padding(KEYPAD_PADDING)
+        .width(keypad_width)
+        .height(keypad_height)
         .style(keypad_container_style)
         .into()
 }
+
+// the size of the whole keypad - the grid, its heading and the padding
+pub fn keypad_size(keypad_view: &KeypadView) -> (f32, f32) {
+    let (width, height) = spanned_size(GridSpan::new(keypad_view.rows, keypad_view.columns));
+
+    (
+        width + 2.0 * KEYPAD_PADDING,
+        height + HEADING_HEIGHT + HEADING_SPACING + 2.0 * KEYPAD_PADDING,
+    )
+}
+
+// where a cell starts, pushed in from the top left - positions are 1 based
+fn cell_offset(position: GridPosition) -> Padding {
+    Padding {
+        top: position.row.saturating_sub(1) as f32 * (KEY_HEIGHT + KEY_SPACING),
+        right: 0.0,
+        bottom: 0.0,
+        left: position.column.saturating_sub(1) as f32 * (KEY_WIDTH + KEY_SPACING),
+    }
+}
+
+// the size of a run of cells, including the spacing inside it
+fn spanned_size(span: GridSpan) -> (f32, f32) {
+    let cells = |count: usize, size: f32| match count {
+        0 => 0.0,
+        _ => count as f32 * size + (count - 1) as f32 * KEY_SPACING,
+    };
+
+    (
+        cells(span.column_span, KEY_WIDTH),
+        cells(span.row_span, KEY_HEIGHT),
+    )
+}
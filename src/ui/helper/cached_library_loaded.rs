@@ -0,0 +1,42 @@
+// handler for the cached library - if there is one it's swapped straight
+// in and we're loaded, otherwise we start loading from the panel list.
+// it still goes through validation, a library that fails it is loaded
+// from the files instead
+
+use cosmic::app::Task;
+
+use crate::app::AppState;
+use crate::app::InitState;
+use crate::app::Message;
+use crate::app::UiModel;
+use crate::data::AssembledLibrary;
+
+impl UiModel {
+    pub fn cached_library_loaded(
+        &mut self,
+        library: Option<Box<AssembledLibrary>>,
+    ) -> Task<Message> {
+        let Some(library) = library else {
+            return Task::done(cosmic::action::app(Message::LoadPanelList));
+        };
+
+        let AssembledLibrary {
+            panels,
+            keypads,
+            keys,
+            keygrids,
+            ..
+        } = *library;
+        (self.panels, self.keypads, self.keys, self.keygrids) = (panels, keypads, keys, keygrids);
+
+        if !self.validate_library().is_empty() || self.panels.len() == 0 {
+            (self.panels, self.keypads, self.keys, self.keygrids) = Default::default();
+            return Task::done(cosmic::action::app(Message::LoadPanelList));
+        }
+
+        self.rebuild_navbar();
+        Task::done(cosmic::action::app(Message::ChangeAppState(
+            AppState::Init(InitState::Loaded),
+        )))
+    }
+}
@@ -0,0 +1,40 @@
+// handler for key loaded - we stash the Key by its KeyId
+// and then "tick" the Keys async count
+
+use cosmic::app::Task;
+use std::io::ErrorKind;
+
+use crate::app::Message;
+use crate::app::UiModel;
+use crate::data::DataError;
+use crate::data::DataErrorKind;
+use crate::data::Key;
+use crate::ui::KeyId;
+
+impl UiModel {
+    pub fn key_loaded(
+        &mut self,
+        result: Result<Key, DataError>,
+        key_id: KeyId,
+        count: usize,
+    ) -> Task<Message> {
+        self.keys.track_async(count);
+
+        match result {
+            Ok(key) => self.keys.insert(key_id, key),
+
+            // keep going - the error ends up in the validation report, a
+            // missing file is reported there as an unknown key
+            Err(error) => {
+                if !matches!(error.kind, DataErrorKind::Io(ErrorKind::NotFound, _)) {
+                    self.load_errors.push(error);
+                }
+            }
+        }
+
+        match self.keys.async_finished() {
+            true => Task::done(cosmic::action::app(Message::KeyGridsLoaded)),
+            false => Task::none(),
+        }
+    }
+}
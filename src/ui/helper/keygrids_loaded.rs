@@ -0,0 +1,55 @@
+// handler for keygrids loaded - every key is back so we lay them out in
+// the keygrids by (KeypadId, GridPosition), then validate the lot and
+// either move on or hand the whole report to the error view
+//
+// only a clean library is written back to the startup cache, along with
+// the panels, keypads and keygrids it was assembled into
+
+use cosmic::app::Task;
+
+use crate::app::AppState;
+use crate::app::InitState;
+use crate::app::Message;
+use crate::app::UiModel;
+use crate::data::helper as data_helper;
+use crate::ui::KeyGridId;
+
+impl UiModel {
+    pub fn keygrids_loaded(&mut self) -> Task<Message> {
+        self.fill_keygrids();
+
+        let report = self.validate_library();
+
+        match report.is_empty() {
+            true => Task::batch([
+                Task::done(cosmic::action::app(Message::ChangeAppState(
+                    AppState::Init(InitState::Loaded),
+                ))),
+                data_helper::save_library_cache(
+                    self.panels.clone(),
+                    self.keypads.clone(),
+                    self.keys.clone(),
+                    self.keygrids.clone(),
+                ),
+            ]),
+            false => Task::done(cosmic::action::app(Message::ValidationFailed(report))),
+        }
+    }
+
+    // (re)build every keygrid from the loaded keypads and keys
+    pub fn fill_keygrids(&mut self) {
+        self.keygrids.clear();
+
+        for (_, keypad_view) in &self.keypads {
+            for key_ref in &keypad_view.keys {
+                if let Some(key) = self.keys.get(&key_ref.key_id) {
+                    let keygrid_id = KeyGridId {
+                        keypad_id: keypad_view.id.clone(),
+                        grid_position: key_ref.grid_position,
+                    };
+                    self.keygrids.insert(&keygrid_id, key);
+                }
+            }
+        }
+    }
+}
@@ -2,9 +2,12 @@
 // and then "tick" the Keypads async count
 
 use cosmic::app::Task;
+use std::io::ErrorKind;
 
 use crate::app::Message;
 use crate::app::UiModel;
+use crate::data::DataError;
+use crate::data::DataErrorKind;
 use crate::data::Keypad;
 use crate::data::KeypadRef;
 use crate::ui::KeypadView;
@@ -12,7 +15,7 @@ use crate::ui::KeypadView;
 impl UiModel {
     pub fn keypad_loaded(
         &mut self,
-        result: Result<Keypad, String>,
+        result: Result<Keypad, DataError>,
         keypad_ref: KeypadRef,
         count: usize,
     ) -> Task<Message> {
@@ -27,8 +30,13 @@ impl UiModel {
                 tasks_batch.push(Task::none());
             }
 
+            // keep going - the error ends up in the validation report, a
+            // missing file is reported there as an unknown keypad
             Err(error) => {
-                tasks_batch.push(Task::done(cosmic::action::app(Message::Error(error))));
+                if !matches!(error.kind, DataErrorKind::Io(ErrorKind::NotFound, _)) {
+                    self.load_errors.push(error);
+                }
+                tasks_batch.push(Task::none());
             }
         }
 
@@ -1,15 +1,24 @@
-// handler for keypads loaded
+// handler for keypads loaded - we build a list of the unique keys the
+// keypads use and pass it to the LoadKeyGrids message
 use cosmic::app::Task;
 
-use crate::app::AppState;
-use crate::app::InitState;
 use crate::app::Message;
 use crate::app::UiModel;
 
+use crate::data::KeyList;
+
 impl UiModel {
     pub fn keypads_loaded(&mut self) -> Task<Message> {
-        Task::done(cosmic::action::app(Message::ChangeAppState(
-            AppState::Init(InitState::Loaded),
-        )))
+        let mut key_list = KeyList::default();
+
+        for (_, keypad_view) in &self.keypads {
+            for key_ref in &keypad_view.keys {
+                if !key_list.contains(&key_ref.key_id) {
+                    key_list.push(key_ref.key_id.clone());
+                }
+            }
+        }
+
+        Task::done(cosmic::action::app(Message::LoadKeyGrids(key_list)))
     }
 }
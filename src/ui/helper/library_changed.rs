@@ -0,0 +1,25 @@
+// handler for a changed file in the data roots - only reload it if it's
+// something the loaded library actually uses
+
+use cosmic::app::Task;
+
+use crate::app::Message;
+use crate::app::UiModel;
+use crate::data::LibraryChange;
+use crate::data::helper as data_helper;
+
+impl UiModel {
+    pub fn library_changed(&mut self, change: LibraryChange) -> Task<Message> {
+        let in_use = match &change {
+            LibraryChange::PanelList => true,
+            LibraryChange::Panel(panel_ref) => self.panels.contains(&panel_ref.id),
+            LibraryChange::Keypad(keypad_ref) => self.keypads.contains(keypad_ref),
+            LibraryChange::Key(key_id) => self.keys.contains(key_id),
+        };
+
+        match in_use {
+            true => data_helper::reload_library(change),
+            false => Task::none(),
+        }
+    }
+}
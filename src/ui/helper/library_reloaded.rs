@@ -0,0 +1,129 @@
+// handler for a hot reload - swap the reloaded definitions in, rebuild the
+// keygrids and validate. if anything is wrong we put the last good library
+// back and show the problems instead
+//
+// the calculation lives in the AppModel so it's never touched, and the
+// navbar is rebuilt around whichever panel was active
+//
+// the files of a reload that's put back have been parsed into the cache
+// but aren't what's showing - they're dropped from the cache so the library
+// showing can't be saved with their stamps
+
+use cosmic::app::Task;
+
+use crate::app::Message;
+use crate::app::UiModel;
+use crate::data::DataError;
+use crate::data::DataErrorKind;
+use crate::data::DataSource;
+use crate::data::LIBRARY_CACHE;
+use crate::data::LibraryReload;
+use crate::data::PanelList;
+use crate::data::ValidationReport;
+use crate::data::helper as data_helper;
+use crate::ui::KeypadView;
+use crate::ui::PanelView;
+
+impl UiModel {
+    pub fn library_reloaded(
+        &mut self,
+        result: Result<LibraryReload, ValidationReport>,
+    ) -> Task<Message> {
+        let reload = match result {
+            Ok(reload) => reload,
+            Err(report) => {
+                self.reload_errors = report;
+                return Task::none();
+            }
+        };
+
+        let mut reloaded: Vec<DataSource> = Vec::new();
+        reloaded.extend(reload.keys.iter().map(|(_, key)| key.source().clone()));
+        reloaded.extend(
+            reload
+                .keypads
+                .iter()
+                .map(|(_, keypad)| keypad.source.clone()),
+        );
+        reloaded.extend(reload.panels.iter().map(|panel| panel.source.clone()));
+        if reload.panel_list.is_some() {
+            reloaded.extend(data_helper::find_source(&PanelList::ron_path()).ok());
+        }
+
+        let last_good = (
+            self.panels.clone(),
+            self.keypads.clone(),
+            self.keys.clone(),
+            self.keygrids.clone(),
+        );
+
+        for (key_id, key) in reload.keys {
+            self.keys.insert(key_id, key);
+        }
+
+        for (keypad_ref, keypad) in reload.keypads {
+            self.keypads.insert(&keypad_ref, &KeypadView::from(keypad));
+        }
+
+        // a new panel list decides which panels there are and in what order
+        if reload.panel_list.is_some() {
+            self.panels.clear();
+        }
+        for panel in reload.panels {
+            self.panels.replace(PanelView::from(panel));
+        }
+
+        self.fill_keygrids();
+
+        // a panel list with nothing in it would leave nothing to show
+        let mut report = self.validate_library();
+        if self.panels.len() == 0 {
+            report.push(DataError::new(DataErrorKind::NoPanels));
+        }
+        if !report.is_empty() {
+            (self.panels, self.keypads, self.keys, self.keygrids) = last_good;
+            if let Ok(mut cache) = LIBRARY_CACHE.lock() {
+                for source in &reloaded {
+                    cache.invalidate(source);
+                }
+            }
+            self.reload_errors = report;
+            return Task::none();
+        }
+
+        self.reload_errors = ValidationReport::default();
+        self.rebuild_navbar();
+
+        data_helper::save_library_cache(
+            self.panels.clone(),
+            self.keypads.clone(),
+            self.keys.clone(),
+            self.keygrids.clone(),
+        )
+    }
+
+    pub fn rebuild_navbar(&mut self) {
+        let active_panel_id = self.navbar.active_data::<String>().cloned();
+        self.navbar.clear();
+
+        for panel_view in &self.panels {
+            let entity = self
+                .navbar
+                .insert()
+                .data(panel_view.id.clone())
+                .text(panel_view.label.clone())
+                .id();
+
+            if active_panel_id.as_ref() == Some(&panel_view.id) {
+                self.navbar.activate(entity);
+            }
+        }
+
+        // the active panel went away - fall back to the first one
+        if self.navbar.active_data::<String>().is_none()
+            && let Some(entity) = self.navbar.iter().next()
+        {
+            self.navbar.activate(entity);
+        }
+    }
+}
@@ -3,11 +3,12 @@
 
 use crate::app::Message;
 use crate::app::UiModel;
+use crate::data::DataError;
 use crate::data::PanelList;
 use cosmic::app::Task;
 
 impl UiModel {
-    pub fn panel_list_loaded(&self, result: Result<PanelList, String>) -> Task<Message> {
+    pub fn panel_list_loaded(&self, result: Result<PanelList, DataError>) -> Task<Message> {
         match result {
             Ok(panel_list) => Task::done(cosmic::action::app(Message::LoadPanels(panel_list))),
 
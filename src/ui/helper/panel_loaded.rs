@@ -5,11 +5,16 @@ use cosmic::app::Task;
 
 use crate::app::Message;
 use crate::app::UiModel;
+use crate::data::DataError;
 use crate::data::Panel;
 use crate::ui::PanelView;
 
 impl UiModel {
-    pub fn panel_loaded(&mut self, result: Result<Panel, String>, count: usize) -> Task<Message> {
+    pub fn panel_loaded(
+        &mut self,
+        result: Result<Panel, DataError>,
+        count: usize,
+    ) -> Task<Message> {
         self.panels.track_async(count);
 
         let mut tasks_batch = Vec::new();
@@ -36,8 +41,10 @@ impl UiModel {
                 tasks_batch.push(Task::none());
             }
 
+            // keep going - the error ends up in the validation report
             Err(data_error) => {
-                tasks_batch.push(Task::done(cosmic::action::app(Message::Error(data_error))));
+                self.load_errors.push(data_error);
+                tasks_batch.push(Task::none());
             }
         }
 
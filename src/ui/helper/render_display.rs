@@ -0,0 +1,35 @@
+// render the calculator display - the current entry or the last result,
+// with the names of any memory registers that hold something on the left
+
+use cosmic::Element;
+use cosmic::iced::{Alignment, Length};
+use cosmic::widget::{container, row, text};
+
+use crate::app::Message;
+use crate::app::UiModel;
+use crate::calc::CalcState;
+use crate::ui::style::keypad_container_style;
+
+impl UiModel {
+    pub fn render_display(&self, calc: &CalcState) -> Element<'static, Message> {
+        let memory = calc.memory().names().collect::<Vec<_>>().join(" ");
+        let memory_indicator = text::caption(memory);
+
+        let display_text = text(calc.display())
+            .size(32)
+            .width(Length::Fill)
+            .align_x(Alignment::End);
+
+        let display = row::with_capacity(2)
+            .spacing(10)
+            .align_y(Alignment::Center)
+            .push(memory_indicator)
+            .push(display_text);
+
+        container(display)
+            .padding(15)
+            .width(Length::Fill)
+            .style(keypad_container_style)
+            .into()
+    }
+}
@@ -0,0 +1,79 @@
+// render the error report - for each problem what went wrong, which file
+// and where in it, and which panel, keypad or key was being loaded
+//
+// a failed hot reload gets the same cards, but above the calculator with
+// a note that the last good library is still in use
+
+use cosmic::Element;
+use cosmic::iced::Length;
+use cosmic::widget::{button, column, container, row, scrollable, text};
+
+use crate::app::Message;
+use crate::app::UiModel;
+use crate::data::DataError;
+use crate::data::ValidationReport;
+use crate::ui::style::keypad_container_style;
+
+impl UiModel {
+    pub fn render_error(&self, report: &ValidationReport) -> Element<'static, Message> {
+        let mut problems = column::with_capacity(report.len() + 1)
+            .spacing(10)
+            .push(text::title4(format!(
+                "Unable to load the calculator library ({} problems)",
+                report.len()
+            )));
+
+        for error in report {
+            problems = problems.push(render_problem(error));
+        }
+
+        scrollable(problems).into()
+    }
+
+    pub fn render_reload_errors(&self) -> Option<Element<'static, Message>> {
+        if self.reload_errors.is_empty() {
+            return None;
+        }
+
+        let heading = row::with_capacity(2)
+            .spacing(10)
+            .push(
+                text::heading(format!(
+                    "Reload failed ({} problems) - still using the last good library",
+                    self.reload_errors.len()
+                ))
+                .width(Length::Fill),
+            )
+            .push(button::text("Dismiss").on_press(Message::DismissReloadErrors));
+
+        let mut problems = column::with_capacity(self.reload_errors.len() + 1)
+            .spacing(10)
+            .push(heading);
+
+        for error in &self.reload_errors {
+            problems = problems.push(render_problem(error));
+        }
+
+        Some(scrollable(problems).height(Length::Shrink).into())
+    }
+}
+
+fn render_problem(error: &DataError) -> Element<'static, Message> {
+    let mut details = column::with_capacity(3)
+        .spacing(5)
+        .push(text(error.kind.to_string()));
+
+    if let Some(location) = error.location() {
+        details = details.push(text::caption(format!("File: {}", location)));
+    }
+
+    if let Some(id) = &error.id {
+        details = details.push(text::caption(format!("While loading: {}", id)));
+    }
+
+    container(details)
+        .padding(15)
+        .width(Length::Fill)
+        .style(keypad_container_style)
+        .into()
+}
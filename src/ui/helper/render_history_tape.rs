@@ -0,0 +1,92 @@
+// render the history tape - a scrollable strip down the side of the panel,
+// one card per calculation with the newest at the bottom
+//
+// {Ans} in a key's term reaches back into these results, newest first
+//
+// keys are shown by their label, or by their id if the key has gone from
+// the library since
+
+use cosmic::Element;
+use cosmic::iced::{Alignment, Length};
+use cosmic::widget::{button, column, container, row, scrollable, text, tooltip};
+
+use crate::app::Message;
+use crate::app::UiModel;
+use crate::history::HistoryEntry;
+use crate::history::HistoryTape;
+use crate::ui::style::keypad_container_style;
+
+impl UiModel {
+    pub fn render_history_tape(
+        &self,
+        history: &HistoryTape,
+        session: Option<&String>,
+    ) -> Element<'static, Message> {
+        // the heading says which session the tape is saved to, if any
+        let heading = match session {
+            Some(session) => format!("History - {}", session),
+            None => "History".to_string(),
+        };
+        let mut tape = column::with_capacity(history.len() + 1)
+            .spacing(10)
+            .push(text::heading(heading));
+
+        if history.is_empty() {
+            tape = tape.push(text::caption("Finished calculations show up here"));
+        }
+
+        for (index, entry) in history.into_iter().enumerate() {
+            tape = tape.push(self.render_history_entry(index, entry));
+        }
+
+        container(scrollable(tape).anchor_bottom())
+            .padding(15)
+            .width(Length::Fixed(260.0))
+            .height(Length::Fill)
+            .style(keypad_container_style)
+            .into()
+    }
+
+    // the result goes back into the entry when clicked, and replay runs the
+    // same keys again on whatever is in the entry now
+    fn render_history_entry(
+        &self,
+        index: usize,
+        entry: &HistoryEntry,
+    ) -> Element<'static, Message> {
+        let key_labels = entry
+            .keys
+            .iter()
+            .map(|key_id| match self.keys.get(key_id) {
+                Some(key) => key.label.clone(),
+                None => key_id.to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        column::with_capacity(4)
+            .spacing(2)
+            .push(text::caption(
+                entry.timestamp.format("%H:%M:%S").to_string(),
+            ))
+            .push(text(entry.expression.clone()))
+            .push(
+                row::with_capacity(2)
+                    .spacing(5)
+                    .align_y(Alignment::Center)
+                    .push(
+                        button::text("Replay")
+                            .on_press(Message::ReplayKeys(index))
+                            .width(Length::Fill),
+                    )
+                    .push(tooltip(
+                        button::text(format!("= {}", entry.result))
+                            .on_press(Message::RecallResult(index)),
+                        text("Use this result"),
+                        tooltip::Position::Top,
+                    )),
+            )
+            .push(text::caption(key_labels))
+            .into()
+    }
+}
@@ -12,10 +12,6 @@ impl UiModel {
     pub fn render_keypad_view(&self, keypad_ref: &KeypadRef) -> Element<'static, Message> {
         let active_keypad_view = self.keypads.get(&keypad_ref);
 
-        build_button_grid(
-            &active_keypad_view.keygrid,
-            active_keypad_view.rows,
-            active_keypad_view.columns,
-        )
+        build_button_grid(&self.keygrids, active_keypad_view)
     }
 }
@@ -1,23 +1,75 @@
 // ok here's where we start building dipslay widgets to represent
 // the PnaelView - which will contain 1 or more KeypadViews
+//
+// the keypads are laid out on the panel's rows x columns grid using the
+// row and column of each KeypadRef. every column is as wide as its widest
+// keypad and every row as tall as its tallest, and each cell - empty or
+// not - is given exactly that size so the keypads line up both ways
 
 use cosmic::Element;
-use cosmic::widget::{container, row};
+use cosmic::widget::{Space, column, container, row, text};
 
 use crate::app::UiModel;
+use crate::ui::GridPosition;
+use crate::ui::helper::keypad_size;
 
 use crate::app::Message;
 
 impl UiModel {
     pub fn render_panel_view(&self) -> Element<'static, Message> {
-        let active_panel_id = self.navbar.active_data::<String>().unwrap();
-        let active_panel = self.panels.get(&active_panel_id);
-
-        let mut panel_row = row::with_capacity(active_panel.keypads.len());
+        // a library with no panels has nothing to activate
+        let Some(active_panel_id) = self
+            .navbar
+            .active_data::<String>()
+            .filter(|panel_id| self.panels.contains(panel_id))
+        else {
+            return container(text::body("There are no panels to show")).into();
+        };
+        let active_panel = self.panels.get(active_panel_id);
 
+        let mut column_widths = vec![0.0_f32; active_panel.columns];
+        let mut row_heights = vec![0.0_f32; active_panel.rows];
         for keypad_ref in active_panel {
-            panel_row = panel_row.push(self.render_keypad_view(&keypad_ref));
+            let (width, height) = keypad_size(self.keypads.get(keypad_ref));
+            if let Some(column_width) = column_widths.get_mut(keypad_ref.column.wrapping_sub(1)) {
+                *column_width = column_width.max(width);
+            }
+            if let Some(row_height) = row_heights.get_mut(keypad_ref.row.wrapping_sub(1)) {
+                *row_height = row_height.max(height);
+            }
         }
-        container(panel_row).into()
+
+        let mut panel_grid = column::with_capacity(active_panel.rows).spacing(10);
+
+        for (grid_row, &row_height) in (1..).zip(&row_heights) {
+            let mut panel_row = row::with_capacity(active_panel.columns).spacing(10);
+
+            for (grid_column, &column_width) in (1..).zip(&column_widths) {
+                let position = GridPosition::new(grid_row, grid_column);
+
+                panel_row = match active_panel
+                    .keypads
+                    .iter()
+                    .find(|keypad_ref| keypad_ref.grid_position() == position)
+                {
+                    Some(keypad_ref) => panel_row.push(
+                        container(self.render_keypad_view(keypad_ref))
+                            .width(column_width)
+                            .height(row_height),
+                    ),
+                    None => panel_row.push(Space::new(column_width, row_height)),
+                };
+            }
+
+            panel_grid = panel_grid.push(panel_row);
+        }
+
+        // and a caption saying where the panel definition came from
+        let panel_column = column::with_capacity(2)
+            .push(panel_grid)
+            .push(text::caption(active_panel.source.to_string()))
+            .spacing(5);
+
+        container(panel_column).into()
     }
 }
@@ -0,0 +1,54 @@
+// render the settings page for the context drawer
+
+use cosmic::Element;
+use cosmic::widget::{dropdown, settings};
+use rust_decimal::Decimal;
+use std::sync::LazyLock;
+
+use crate::app::Message;
+use crate::app::UiModel;
+use crate::calc::EngineKind;
+use crate::config::Config;
+
+// the dropdown borrows its labels so they have to live somewhere
+static ENGINE_NAMES: LazyLock<Vec<&'static str>> = LazyLock::new(|| {
+    EngineKind::AVAILABLE
+        .iter()
+        .map(EngineKind::as_str)
+        .collect()
+});
+
+// results can be rounded to anything from 0 to 28 decimal places
+static PRECISION_NAMES: LazyLock<Vec<String>> = LazyLock::new(|| {
+    (0..=Decimal::MAX_SCALE)
+        .map(|places| places.to_string())
+        .collect()
+});
+
+impl UiModel {
+    pub fn render_settings(&self, config: &Config) -> Element<'static, Message> {
+        let selected_engine = EngineKind::AVAILABLE
+            .iter()
+            .position(|engine_kind| *engine_kind == config.engine);
+
+        settings::section()
+            .title("Calculator")
+            .add(settings::item(
+                "Engine",
+                dropdown(
+                    ENGINE_NAMES.as_slice(),
+                    selected_engine,
+                    Message::SelectEngine,
+                ),
+            ))
+            .add(settings::item(
+                "Decimal places",
+                dropdown(
+                    PRECISION_NAMES.as_slice(),
+                    Some(config.precision.min(Decimal::MAX_SCALE) as usize),
+                    Message::SelectPrecision,
+                ),
+            ))
+            .into()
+    }
+}
@@ -0,0 +1,378 @@
+// the validation pass over everything that's been loaded - run before the
+// UI is built so a broken reference or a clash on the grid gets reported
+// instead of blowing up in build_button_grid
+//
+// every problem goes into the one report, along with anything that
+// already failed to load
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use crate::app::UiModel;
+use crate::data::DataError;
+use crate::data::DataErrorKind;
+use crate::data::KeyRef;
+use crate::data::KeypadRef;
+use crate::data::ValidationReport;
+use crate::ui::GridPosition;
+use crate::ui::GridSpan;
+use crate::ui::KeypadView;
+use crate::ui::PanelView;
+
+impl UiModel {
+    pub fn validate_library(&self) -> ValidationReport {
+        let mut report = self.load_errors.clone();
+
+        for panel_view in &self.panels {
+            report.extend(self.validate_panel(panel_view));
+        }
+
+        for (keypad_ref, keypad_view) in &self.keypads {
+            report.extend(self.validate_keypad(keypad_ref, keypad_view));
+        }
+
+        report
+    }
+
+    // every keypad has to exist and have a cell of its own on the panel grid
+    pub fn validate_panel(&self, panel_view: &PanelView) -> ValidationReport {
+        let error = |kind| {
+            DataError::new(kind)
+                .with_path(&panel_view.source.path)
+                .with_id(&panel_view.id)
+        };
+
+        let mut report = ValidationReport::default();
+        let mut positions: HashSet<GridPosition> = HashSet::new();
+
+        for keypad_ref in panel_view {
+            let position = keypad_ref.grid_position();
+
+            if !self.keypads.contains(keypad_ref) {
+                report.push(error(DataErrorKind::UnknownKeypad(keypad_ref.id.clone())));
+            }
+
+            if position.row == 0
+                || position.column == 0
+                || position.row > panel_view.rows
+                || position.column > panel_view.columns
+            {
+                report.push(error(DataErrorKind::KeypadOutOfBounds {
+                    keypad_id: keypad_ref.id.clone(),
+                    position,
+                    rows: panel_view.rows,
+                    columns: panel_view.columns,
+                }));
+                continue;
+            }
+
+            if !positions.insert(position) {
+                report.push(error(DataErrorKind::DuplicateKeypadPosition(position)));
+            }
+        }
+
+        report
+    }
+
+    pub fn validate_keypad(
+        &self,
+        keypad_ref: &KeypadRef,
+        keypad_view: &KeypadView,
+    ) -> ValidationReport {
+        let error = |kind| {
+            DataError::new(kind)
+                .with_path(&keypad_view.source.path)
+                .with_id(&keypad_ref.id)
+        };
+
+        let mut report = ValidationReport::default();
+        let mut origins: HashSet<GridPosition> = HashSet::new();
+        let mut covered: HashMap<GridPosition, &KeyRef> = HashMap::new();
+
+        for key_ref in &keypad_view.keys {
+            let position = key_ref.grid_position;
+            let span = key_ref.grid_span;
+
+            if !self.keys.contains(&key_ref.key_id) {
+                report.push(error(DataErrorKind::UnknownKey(key_ref.key_id.clone())));
+            }
+
+            if span.row_span == 0 || span.column_span == 0 {
+                report.push(error(DataErrorKind::EmptySpan(
+                    key_ref.key_id.clone(),
+                    position,
+                )));
+                continue;
+            }
+
+            if !origins.insert(position) {
+                report.push(error(DataErrorKind::DuplicatePosition(position)));
+                continue;
+            }
+
+            // positions are 1 based, the span has to fit inside the grid too
+            let Some(last) = last_cell(position, span).filter(|last| {
+                position.row != 0
+                    && position.column != 0
+                    && last.row <= keypad_view.rows
+                    && last.column <= keypad_view.columns
+            }) else {
+                report.push(error(DataErrorKind::OutOfBounds {
+                    key_id: key_ref.key_id.clone(),
+                    position,
+                    span,
+                    rows: keypad_view.rows,
+                    columns: keypad_view.columns,
+                }));
+                continue;
+            };
+
+            let cells = (position.row..=last.row).flat_map(|row| {
+                (position.column..=last.column).map(move |column| GridPosition::new(row, column))
+            });
+
+            for cell in cells {
+                match covered.get(&cell) {
+                    Some(other) => {
+                        report.push(error(DataErrorKind::OverlappingSpan {
+                            key_id: key_ref.key_id.clone(),
+                            other_id: other.key_id.clone(),
+                            position: cell,
+                        }));
+                        break;
+                    }
+                    None => {
+                        covered.insert(cell, key_ref);
+                    }
+                }
+            }
+        }
+
+        // spacers and separators fill cells too, but only ones no key is using
+        let mut filled: HashSet<GridPosition> = HashSet::new();
+        for spacer_ref in &keypad_view.spacers {
+            let position = spacer_ref.grid_position();
+            let span = spacer_ref.grid_span();
+
+            let Some(last) = last_cell(position, span).filter(|last| {
+                position.row != 0
+                    && position.column != 0
+                    && last.row <= keypad_view.rows
+                    && last.column <= keypad_view.columns
+            }) else {
+                report.push(error(DataErrorKind::SpacerOutOfBounds {
+                    position,
+                    span,
+                    rows: keypad_view.rows,
+                    columns: keypad_view.columns,
+                }));
+                continue;
+            };
+
+            let cells = (position.row..=last.row).flat_map(|row| {
+                (position.column..=last.column).map(move |column| GridPosition::new(row, column))
+            });
+
+            for cell in cells {
+                if covered.contains_key(&cell) || !filled.insert(cell) {
+                    report.push(error(DataErrorKind::OverlappingSpacer(cell)));
+                    break;
+                }
+            }
+        }
+
+        report
+    }
+}
+
+// the bottom right cell of a span - None for an empty span, or one so big
+// it runs off the end of a usize
+fn last_cell(position: GridPosition, span: GridSpan) -> Option<GridPosition> {
+    Some(GridPosition::new(
+        position.row.checked_add(span.row_span.checked_sub(1)?)?,
+        position
+            .column
+            .checked_add(span.column_span.checked_sub(1)?)?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::Key;
+    use crate::data::KeyBase;
+    use crate::data::KeyType;
+    use crate::data::SpacerRef;
+    use crate::ui::KeyId;
+    use rust_decimal::Decimal;
+
+    // a 2x2 keypad on a 1x2 panel, with keys "one" and "two" in the library
+    fn ui_model(keys: Vec<KeyRef>, keypads: Vec<KeypadRef>) -> UiModel {
+        let mut ui = UiModel::default();
+        for id in ["one", "two"] {
+            let base = KeyBase {
+                id: id.to_string(),
+                label: id.to_string(),
+                tooltip_text: String::new(),
+            };
+            let key_type = KeyType::Operand {
+                value: Decimal::ONE,
+            };
+            ui.keys
+                .insert(KeyId::new("test", id), Key::new(base, key_type));
+        }
+
+        let keypad_view = KeypadView {
+            rows: 2,
+            columns: 2,
+            keys,
+            ..Default::default()
+        };
+        ui.keypads.insert(&KeypadRef::new("pad"), &keypad_view);
+
+        ui.panels.push(PanelView {
+            id: "panel".to_string(),
+            rows: 1,
+            columns: 2,
+            keypads,
+            ..Default::default()
+        });
+        ui
+    }
+
+    fn key(id: &str, row: usize, column: usize, row_span: usize, column_span: usize) -> KeyRef {
+        KeyRef {
+            key_id: KeyId::new("test", id),
+            grid_position: GridPosition::new(row, column),
+            grid_span: GridSpan::new(row_span, column_span),
+        }
+    }
+
+    fn keypad(id: &str, row: usize, column: usize) -> KeypadRef {
+        KeypadRef {
+            id: id.to_string(),
+            row,
+            column,
+        }
+    }
+
+    fn kinds(ui: &UiModel) -> Vec<DataErrorKind> {
+        ui.validate_library()
+            .into_iter()
+            .map(|error| error.kind)
+            .collect()
+    }
+
+    #[test]
+    fn a_sound_library() {
+        let ui = ui_model(
+            vec![key("one", 1, 1, 1, 2), key("two", 2, 1, 1, 1)],
+            vec![keypad("pad", 1, 1)],
+        );
+        assert!(ui.validate_library().is_empty());
+    }
+
+    #[test]
+    fn duplicate_positions() {
+        let ui = ui_model(
+            vec![key("one", 1, 1, 1, 1), key("two", 1, 1, 1, 1)],
+            vec![keypad("pad", 1, 1), keypad("pad", 1, 1)],
+        );
+        let kinds = kinds(&ui);
+        assert_eq!(kinds.len(), 2);
+        assert!(matches!(
+            kinds[0],
+            DataErrorKind::DuplicateKeypadPosition(GridPosition { row: 1, column: 1 })
+        ));
+        assert!(matches!(
+            kinds[1],
+            DataErrorKind::DuplicatePosition(GridPosition { row: 1, column: 1 })
+        ));
+    }
+
+    #[test]
+    fn out_of_bounds() {
+        let ui = ui_model(
+            vec![key("one", 2, 2, 1, 2), key("two", 0, 1, 1, 1)],
+            vec![keypad("pad", 1, 3)],
+        );
+        let kinds = kinds(&ui);
+        assert_eq!(kinds.len(), 3);
+        assert!(matches!(
+            &kinds[0],
+            DataErrorKind::KeypadOutOfBounds { keypad_id, rows: 1, columns: 2, .. }
+                if keypad_id == "pad"
+        ));
+        assert!(matches!(
+            &kinds[1],
+            DataErrorKind::OutOfBounds { key_id, rows: 2, columns: 2, .. } if key_id.id == "one"
+        ));
+        assert!(matches!(
+            &kinds[2],
+            DataErrorKind::OutOfBounds { key_id, .. } if key_id.id == "two"
+        ));
+    }
+
+    #[test]
+    fn overlapping_spans() {
+        let ui = ui_model(
+            vec![key("one", 1, 1, 2, 1), key("two", 2, 1, 1, 2)],
+            vec![keypad("pad", 1, 1)],
+        );
+        let kinds = kinds(&ui);
+        assert_eq!(kinds.len(), 1);
+        assert!(matches!(
+            &kinds[0],
+            DataErrorKind::OverlappingSpan {
+                key_id,
+                other_id,
+                position: GridPosition { row: 2, column: 1 },
+            } if key_id.id == "two" && other_id.id == "one"
+        ));
+    }
+
+    #[test]
+    fn unknown_references() {
+        let ui = ui_model(
+            vec![key("three", 1, 1, 1, 1)],
+            vec![keypad("pad", 1, 1), keypad("missing", 1, 2)],
+        );
+        let kinds = kinds(&ui);
+        assert_eq!(kinds.len(), 2);
+        assert!(matches!(&kinds[0], DataErrorKind::UnknownKeypad(id) if id == "missing"));
+        assert!(matches!(&kinds[1], DataErrorKind::UnknownKey(key_id) if key_id.id == "three"));
+    }
+
+    #[test]
+    fn huge_spans() {
+        let mut ui = ui_model(
+            vec![
+                key("one", 2, 1, usize::MAX, 1),
+                key("two", 1, 2, 1, usize::MAX),
+            ],
+            vec![keypad("pad", 1, 1)],
+        );
+        let spacers = KeypadView {
+            rows: 2,
+            columns: 2,
+            spacers: vec![SpacerRef::Spacer {
+                grid_position: GridPosition::new(1, 1),
+                grid_span: GridSpan::new(usize::MAX, usize::MAX),
+            }],
+            ..Default::default()
+        };
+        ui.keypads.insert(&KeypadRef::new("spacers"), &spacers);
+
+        let kinds = kinds(&ui);
+        assert_eq!(kinds.len(), 3);
+        assert!(matches!(
+            &kinds[0],
+            DataErrorKind::OutOfBounds { key_id, .. } if key_id.id == "one"
+        ));
+        assert!(matches!(
+            &kinds[1],
+            DataErrorKind::OutOfBounds { key_id, .. } if key_id.id == "two"
+        ));
+        assert!(matches!(&kinds[2], DataErrorKind::SpacerOutOfBounds { .. }));
+    }
+}
@@ -8,6 +8,7 @@ mod keygrids;
 mod keypad_id;
 mod keypad_view;
 mod keypads;
+mod keys;
 mod panel_view;
 mod panels;
 
@@ -19,6 +20,7 @@ pub(crate) use keygrids::KeyGrids;
 pub(crate) use keypad_id::KeypadId;
 pub(crate) use keypad_view::KeypadView;
 pub(crate) use keypads::Keypads;
+pub(crate) use keys::Keys;
 
 pub(crate) use panel_view::PanelView;
 pub(crate) use panels::Panels;
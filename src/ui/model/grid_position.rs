@@ -16,3 +16,9 @@ impl GridPosition {
         GridPosition { row, column }
     }
 }
+
+impl std::fmt::Display for GridPosition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "row {}, column {}", self.row, self.column)
+    }
+}
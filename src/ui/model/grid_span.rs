@@ -2,7 +2,7 @@
 // this will allow us to have keys that span a row or a column
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Default, Serialize, Deserialize, Hash, Eq, PartialEq, Copy, Clone)]
+#[derive(Debug, Serialize, Deserialize, Hash, Eq, PartialEq, Copy, Clone)]
 pub struct GridSpan {
     #[serde(default = "GridSpan::default_span")]
     pub row_span: usize,
@@ -23,3 +23,10 @@ impl GridSpan {
         1
     }
 }
+
+// a key with no span given covers just its own cell
+impl Default for GridSpan {
+    fn default() -> Self {
+        GridSpan::new(1, 1)
+    }
+}
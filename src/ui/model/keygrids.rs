@@ -14,8 +14,8 @@ pub struct KeyGrids {
 }
 
 impl KeyGrids {
-    pub fn get(&self, keygrid_ref: &KeyGridId) -> &Key {
-        self.keys.get(keygrid_ref).unwrap()
+    pub fn get(&self, keygrid_ref: &KeyGridId) -> Option<&Key> {
+        self.keys.get(keygrid_ref)
     }
 
     pub fn insert(&mut self, keygrid_ref: &KeyGridId, key: &Key) {
@@ -26,6 +26,10 @@ impl KeyGrids {
         self.keys.len()
     }
 
+    pub fn clear(&mut self) {
+        self.keys.clear();
+    }
+
     pub fn track_async(&mut self, count: usize) {
         self.countdown.track(count);
     }
@@ -3,8 +3,10 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::data::DataSource;
 use crate::data::KeyRef;
 use crate::data::Keypad;
+use crate::data::SpacerRef;
 use crate::ui::KeypadId;
 
 //  bow read our Keypads structure from the RON file
@@ -17,6 +19,8 @@ pub struct KeypadView {
     pub rows: usize,
     pub columns: usize,
     pub keys: Vec<KeyRef>,
+    pub spacers: Vec<SpacerRef>,
+    pub source: DataSource,
 }
 
 impl From<Keypad> for KeypadView {
@@ -28,6 +32,8 @@ impl From<Keypad> for KeypadView {
             rows: keypad.rows,
             columns: keypad.columns,
             keys: keypad.keys,
+            spacers: keypad.spacers,
+            source: keypad.source,
         }
     }
 }
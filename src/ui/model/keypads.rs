@@ -23,6 +23,10 @@ impl Keypads {
             .insert(keypad_ref.clone(), keypad_view.clone());
     }
 
+    pub fn contains(&self, keypad_ref: &KeypadRef) -> bool {
+        self.keypad_views.contains_key(keypad_ref)
+    }
+
     pub fn len(&self) -> usize {
         self.keypad_views.len()
     }
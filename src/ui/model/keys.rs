@@ -0,0 +1,54 @@
+// the collection of loaded keys - one per KeyId no matter how many
+// keypads use it, the keygrids are filled in from here
+
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+use crate::data::Key;
+use crate::ui::AsyncCountdown;
+use crate::ui::KeyId;
+
+#[derive(Debug, Default, Deserialize, Serialize, Clone)]
+pub struct Keys {
+    keys: IndexMap<KeyId, Key>,
+    countdown: AsyncCountdown,
+}
+
+impl Keys {
+    pub fn get(&self, key_id: &KeyId) -> Option<&Key> {
+        self.keys.get(key_id)
+    }
+
+    pub fn insert(&mut self, key_id: KeyId, key: Key) {
+        self.keys.insert(key_id, key);
+    }
+
+    pub fn contains(&self, key_id: &KeyId) -> bool {
+        self.keys.contains_key(key_id)
+    }
+
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    pub fn track_async(&mut self, count: usize) {
+        self.countdown.track(count);
+    }
+
+    pub fn async_remaining(&self) -> usize {
+        self.countdown.remaining()
+    }
+
+    pub fn async_finished(&self) -> bool {
+        self.countdown.is_zero()
+    }
+}
+
+impl<'a> IntoIterator for &'a Keys {
+    type Item = (&'a KeyId, &'a Key);
+    type IntoIter = indexmap::map::Iter<'a, KeyId, Key>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.keys.iter()
+    }
+}
@@ -1,12 +1,15 @@
 // this is the panel view - it is the skinny structure for
 // the view - this separaters UI from the RON data
 
+use serde::{Deserialize, Serialize};
+
+use crate::data::DataSource;
 use crate::data::KeypadRef;
 use crate::data::Panel;
 
 //  bow read our Keypads structure from the RON file
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Deserialize, Serialize, Clone)]
 pub struct PanelView {
     pub id: String,
     pub label: String,
@@ -14,6 +17,7 @@ pub struct PanelView {
     pub rows: usize,
     pub columns: usize,
     pub keypads: Vec<KeypadRef>,
+    pub source: DataSource,
 }
 
 impl From<Panel> for PanelView {
@@ -25,6 +29,7 @@ impl From<Panel> for PanelView {
             rows: panel.rows,
             columns: panel.columns,
             keypads: panel.keypads,
+            source: panel.source,
         }
     }
 }
@@ -2,10 +2,12 @@
 // and a "get" on a specific panel id when we switch views
 // in the navbar
 
+use serde::{Deserialize, Serialize};
+
 use crate::ui::AsyncCountdown;
 use crate::ui::PanelView;
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Deserialize, Serialize, Clone)]
 pub struct Panels {
     panel_views: Vec<PanelView>,
     countdown: AsyncCountdown,
@@ -23,6 +25,26 @@ impl Panels {
         self.panel_views.push(panel_view);
     }
 
+    pub fn contains(&self, panel_id: &str) -> bool {
+        self.panel_views.iter().any(|panel| panel.id == panel_id)
+    }
+
+    // swap in a reloaded panel, keeping its place in the list
+    pub fn replace(&mut self, panel_view: PanelView) {
+        match self
+            .panel_views
+            .iter_mut()
+            .find(|panel| panel.id == panel_view.id)
+        {
+            Some(panel) => *panel = panel_view,
+            None => self.panel_views.push(panel_view),
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.panel_views.clear();
+    }
+
     pub fn len(&self) -> usize {
         self.panel_views.len()
     }
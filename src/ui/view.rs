@@ -3,24 +3,48 @@
 // events received by widgets will be passed to the update method.
 
 use cosmic::Element;
-use cosmic::widget::text;
+use cosmic::widget::{column, row, text};
 
 use crate::app::AppState;
 use crate::app::InitState;
 use crate::app::Message;
 use crate::app::UiModel;
+use crate::calc::CalcState;
+use crate::history::HistoryTape;
 //use crate::ui::helper::build_button_grid;
 
 impl UiModel {
-    pub fn view(&self, app_state: &AppState) -> Element<'_, Message> {
+    pub fn view(
+        &self,
+        app_state: &AppState,
+        calc: &CalcState,
+        history: &HistoryTape,
+        session: Option<&String>,
+    ) -> Element<'_, Message> {
         use AppState::*;
         use InitState::*;
 
         match app_state {
             Init(Loading) => text("Init Loading").into(),
-            Init(Loaded) => self.render_panel_view(),
+            Init(Loaded) => {
+                let mut loaded = Vec::with_capacity(3);
+                if let Some(reload_errors) = self.render_reload_errors() {
+                    loaded.push(reload_errors);
+                }
+                loaded.push(self.render_display(calc));
+                // the history tape runs down the side of the panel
+                loaded.push(
+                    row::with_capacity(2)
+                        .spacing(10)
+                        .push(self.render_panel_view())
+                        .push(self.render_history_tape(history, session))
+                        .into(),
+                );
+
+                column::with_children(loaded).spacing(10).into()
+            }
             Ready => text("Ready").into(),
-            Error(error) => text(format!("Error: {}", error)).into(),
+            Error(error) => self.render_error(error),
         }
     }
 }